@@ -0,0 +1,187 @@
+//! Benchmarks for generated functions which have a faster alternative, on a
+//! table with 200 parameters. Run them with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Defines `RawParameters`, `ParameterType`, `Parameters` and a `table` macro
+/// with one row per `$variant $field_name $idx $name`, then invokes
+/// `impl_all!` with `$option`s. `$d` is a `$` token, for writing `table`'s
+/// own metavariables.
+macro_rules! bench_table {
+    (($d:tt) [$($option:ident)*] $($variant:ident $field_name:ident $idx:literal $name:literal),*) => {
+        use vst::host::Host;
+        use vst::plugin::HostCallback;
+        use vst::util::AtomicFloat;
+        use vst_utils::*;
+
+        pub struct RawParameters {
+            $(pub $field_name: AtomicFloat,)*
+            pub host: HostCallback,
+        }
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        pub enum ParameterType {
+            $($variant,)*
+        }
+
+        pub struct Parameters {
+            $(pub $field_name: f32,)*
+        }
+
+        impl From<&RawParameters> for Parameters {
+            fn from(raw: &RawParameters) -> Self {
+                Parameters {
+                    $($field_name: raw.$field_name.get(),)*
+                }
+            }
+        }
+
+        macro_rules! table {
+            ($d macro:ident) => {
+                $d macro! {
+                    RawParameters, ParameterType;
+                    $(ParameterType::$variant, $idx, $name, $field_name, 0.5, make_strings_percent;)*
+                }
+            };
+        }
+
+        impl_all!(RawParameters, ParameterType, table $(, $option)*);
+
+        pub fn new() -> RawParameters {
+            RawParameters::default(HostCallback::default())
+        }
+
+        /// Every parameter, in index order.
+        pub fn all() -> Vec<ParameterType> {
+            use std::convert::TryFrom;
+            (0..RawParameters::PARAMETER_COUNT)
+                .map(|index| ParameterType::try_from(index).unwrap())
+                .collect()
+        }
+
+        /// Reads `parameter` through the generated `get_stored`.
+        pub fn read(raw: &RawParameters, parameter: ParameterType) -> f32 {
+            raw.get_stored(parameter)
+        }
+    };
+}
+
+macro_rules! bench_tables {
+    ($($row:tt)*) => {
+        mod matched {
+            bench_table! {($) [] $($row)*}
+        }
+
+        mod jump {
+            bench_table! {($) [jump_table] $($row)*}
+        }
+    };
+}
+
+bench_tables! {
+    P000 p000 0 "Parameter 0", P001 p001 1 "Parameter 1", P002 p002 2 "Parameter 2", P003 p003 3 "Parameter 3",
+    P004 p004 4 "Parameter 4", P005 p005 5 "Parameter 5", P006 p006 6 "Parameter 6", P007 p007 7 "Parameter 7",
+    P008 p008 8 "Parameter 8", P009 p009 9 "Parameter 9", P010 p010 10 "Parameter 10", P011 p011 11 "Parameter 11",
+    P012 p012 12 "Parameter 12", P013 p013 13 "Parameter 13", P014 p014 14 "Parameter 14", P015 p015 15 "Parameter 15",
+    P016 p016 16 "Parameter 16", P017 p017 17 "Parameter 17", P018 p018 18 "Parameter 18", P019 p019 19 "Parameter 19",
+    P020 p020 20 "Parameter 20", P021 p021 21 "Parameter 21", P022 p022 22 "Parameter 22", P023 p023 23 "Parameter 23",
+    P024 p024 24 "Parameter 24", P025 p025 25 "Parameter 25", P026 p026 26 "Parameter 26", P027 p027 27 "Parameter 27",
+    P028 p028 28 "Parameter 28", P029 p029 29 "Parameter 29", P030 p030 30 "Parameter 30", P031 p031 31 "Parameter 31",
+    P032 p032 32 "Parameter 32", P033 p033 33 "Parameter 33", P034 p034 34 "Parameter 34", P035 p035 35 "Parameter 35",
+    P036 p036 36 "Parameter 36", P037 p037 37 "Parameter 37", P038 p038 38 "Parameter 38", P039 p039 39 "Parameter 39",
+    P040 p040 40 "Parameter 40", P041 p041 41 "Parameter 41", P042 p042 42 "Parameter 42", P043 p043 43 "Parameter 43",
+    P044 p044 44 "Parameter 44", P045 p045 45 "Parameter 45", P046 p046 46 "Parameter 46", P047 p047 47 "Parameter 47",
+    P048 p048 48 "Parameter 48", P049 p049 49 "Parameter 49", P050 p050 50 "Parameter 50", P051 p051 51 "Parameter 51",
+    P052 p052 52 "Parameter 52", P053 p053 53 "Parameter 53", P054 p054 54 "Parameter 54", P055 p055 55 "Parameter 55",
+    P056 p056 56 "Parameter 56", P057 p057 57 "Parameter 57", P058 p058 58 "Parameter 58", P059 p059 59 "Parameter 59",
+    P060 p060 60 "Parameter 60", P061 p061 61 "Parameter 61", P062 p062 62 "Parameter 62", P063 p063 63 "Parameter 63",
+    P064 p064 64 "Parameter 64", P065 p065 65 "Parameter 65", P066 p066 66 "Parameter 66", P067 p067 67 "Parameter 67",
+    P068 p068 68 "Parameter 68", P069 p069 69 "Parameter 69", P070 p070 70 "Parameter 70", P071 p071 71 "Parameter 71",
+    P072 p072 72 "Parameter 72", P073 p073 73 "Parameter 73", P074 p074 74 "Parameter 74", P075 p075 75 "Parameter 75",
+    P076 p076 76 "Parameter 76", P077 p077 77 "Parameter 77", P078 p078 78 "Parameter 78", P079 p079 79 "Parameter 79",
+    P080 p080 80 "Parameter 80", P081 p081 81 "Parameter 81", P082 p082 82 "Parameter 82", P083 p083 83 "Parameter 83",
+    P084 p084 84 "Parameter 84", P085 p085 85 "Parameter 85", P086 p086 86 "Parameter 86", P087 p087 87 "Parameter 87",
+    P088 p088 88 "Parameter 88", P089 p089 89 "Parameter 89", P090 p090 90 "Parameter 90", P091 p091 91 "Parameter 91",
+    P092 p092 92 "Parameter 92", P093 p093 93 "Parameter 93", P094 p094 94 "Parameter 94", P095 p095 95 "Parameter 95",
+    P096 p096 96 "Parameter 96", P097 p097 97 "Parameter 97", P098 p098 98 "Parameter 98", P099 p099 99 "Parameter 99",
+    P100 p100 100 "Parameter 100", P101 p101 101 "Parameter 101", P102 p102 102 "Parameter 102", P103 p103 103 "Parameter 103",
+    P104 p104 104 "Parameter 104", P105 p105 105 "Parameter 105", P106 p106 106 "Parameter 106", P107 p107 107 "Parameter 107",
+    P108 p108 108 "Parameter 108", P109 p109 109 "Parameter 109", P110 p110 110 "Parameter 110", P111 p111 111 "Parameter 111",
+    P112 p112 112 "Parameter 112", P113 p113 113 "Parameter 113", P114 p114 114 "Parameter 114", P115 p115 115 "Parameter 115",
+    P116 p116 116 "Parameter 116", P117 p117 117 "Parameter 117", P118 p118 118 "Parameter 118", P119 p119 119 "Parameter 119",
+    P120 p120 120 "Parameter 120", P121 p121 121 "Parameter 121", P122 p122 122 "Parameter 122", P123 p123 123 "Parameter 123",
+    P124 p124 124 "Parameter 124", P125 p125 125 "Parameter 125", P126 p126 126 "Parameter 126", P127 p127 127 "Parameter 127",
+    P128 p128 128 "Parameter 128", P129 p129 129 "Parameter 129", P130 p130 130 "Parameter 130", P131 p131 131 "Parameter 131",
+    P132 p132 132 "Parameter 132", P133 p133 133 "Parameter 133", P134 p134 134 "Parameter 134", P135 p135 135 "Parameter 135",
+    P136 p136 136 "Parameter 136", P137 p137 137 "Parameter 137", P138 p138 138 "Parameter 138", P139 p139 139 "Parameter 139",
+    P140 p140 140 "Parameter 140", P141 p141 141 "Parameter 141", P142 p142 142 "Parameter 142", P143 p143 143 "Parameter 143",
+    P144 p144 144 "Parameter 144", P145 p145 145 "Parameter 145", P146 p146 146 "Parameter 146", P147 p147 147 "Parameter 147",
+    P148 p148 148 "Parameter 148", P149 p149 149 "Parameter 149", P150 p150 150 "Parameter 150", P151 p151 151 "Parameter 151",
+    P152 p152 152 "Parameter 152", P153 p153 153 "Parameter 153", P154 p154 154 "Parameter 154", P155 p155 155 "Parameter 155",
+    P156 p156 156 "Parameter 156", P157 p157 157 "Parameter 157", P158 p158 158 "Parameter 158", P159 p159 159 "Parameter 159",
+    P160 p160 160 "Parameter 160", P161 p161 161 "Parameter 161", P162 p162 162 "Parameter 162", P163 p163 163 "Parameter 163",
+    P164 p164 164 "Parameter 164", P165 p165 165 "Parameter 165", P166 p166 166 "Parameter 166", P167 p167 167 "Parameter 167",
+    P168 p168 168 "Parameter 168", P169 p169 169 "Parameter 169", P170 p170 170 "Parameter 170", P171 p171 171 "Parameter 171",
+    P172 p172 172 "Parameter 172", P173 p173 173 "Parameter 173", P174 p174 174 "Parameter 174", P175 p175 175 "Parameter 175",
+    P176 p176 176 "Parameter 176", P177 p177 177 "Parameter 177", P178 p178 178 "Parameter 178", P179 p179 179 "Parameter 179",
+    P180 p180 180 "Parameter 180", P181 p181 181 "Parameter 181", P182 p182 182 "Parameter 182", P183 p183 183 "Parameter 183",
+    P184 p184 184 "Parameter 184", P185 p185 185 "Parameter 185", P186 p186 186 "Parameter 186", P187 p187 187 "Parameter 187",
+    P188 p188 188 "Parameter 188", P189 p189 189 "Parameter 189", P190 p190 190 "Parameter 190", P191 p191 191 "Parameter 191",
+    P192 p192 192 "Parameter 192", P193 p193 193 "Parameter 193", P194 p194 194 "Parameter 194", P195 p195 195 "Parameter 195",
+    P196 p196 196 "Parameter 196", P197 p197 197 "Parameter 197", P198 p198 198 "Parameter 198", P199 p199 199 "Parameter 199"
+}
+
+/// `parameter_by_name` as it was before it used a hash lookup.
+fn parameter_by_name_linear(name: &str) -> Option<matched::ParameterType> {
+    use std::convert::TryFrom;
+    let &(index, _, _) = matched::RawParameters::META
+        .iter()
+        .find(|(_, row_name, _)| row_name.eq_ignore_ascii_case(name))?;
+    matched::ParameterType::try_from(index).ok()
+}
+
+fn parameter_by_name(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parameter_by_name");
+    let names = ["Parameter 0", "Parameter 100", "parameter 199", "Missing"];
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for name in names {
+                black_box(parameter_by_name_linear(black_box(name)));
+            }
+        })
+    });
+    group.bench_function("hash lookup", |b| {
+        b.iter(|| {
+            for name in names {
+                black_box(matched::RawParameters::parameter_by_name(black_box(name)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn get_ref(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_ref");
+    let raw = matched::new();
+    let parameters = matched::all();
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for &parameter in &parameters {
+                black_box(matched::read(&raw, black_box(parameter)));
+            }
+        })
+    });
+    let raw = jump::new();
+    let parameters = jump::all();
+    group.bench_function("jump table", |b| {
+        b.iter(|| {
+            for &parameter in &parameters {
+                black_box(jump::read(&raw, black_box(parameter)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parameter_by_name, get_ref);
+criterion_main!(benches);
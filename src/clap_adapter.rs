@@ -0,0 +1,241 @@
+//! Implements CLAP's params extension (`clap_plugin_params` from `clap-sys`)
+//! from a parameter table, so a CLAP plugin can be driven by the same table
+//! as the VST plugin. `$table! {impl_clap_params}` implements `ClapParams`
+//! for the raw parameters, and `params_extension` turns that into the
+//! extension the host asks for:
+//!
+//! ```ignore
+//! struct MyPlugin {
+//!     params: RawParameters,
+//! }
+//!
+//! impl ClapPlugin for MyPlugin {
+//!     type Params = RawParameters;
+//!
+//!     unsafe fn params<'a>(plugin: *const clap_plugin) -> &'a RawParameters {
+//!         &(*((*plugin).plugin_data as *const MyPlugin)).params
+//!     }
+//! }
+//!
+//! static PARAMS: clap_plugin_params = params_extension::<MyPlugin>();
+//!
+//! // In the plugin's `get_extension`:
+//! if CStr::from_ptr(id) == CLAP_EXT_PARAMS {
+//!     return &PARAMS as *const clap_plugin_params as *const c_void;
+//! }
+//! ```
+
+use std::ffi::{c_char, CStr};
+
+pub use clap_sys;
+use clap_sys::events::{
+    clap_event_param_value, clap_input_events, clap_output_events, CLAP_CORE_EVENT_SPACE_ID,
+    CLAP_EVENT_PARAM_VALUE,
+};
+use clap_sys::ext::params::{
+    clap_param_info, clap_plugin_params, CLAP_PARAM_IS_AUTOMATABLE, CLAP_PARAM_IS_READONLY,
+    CLAP_PARAM_IS_STEPPED,
+};
+use clap_sys::id::clap_id;
+use clap_sys::plugin::clap_plugin;
+use clap_sys::string_sizes::{CLAP_NAME_SIZE, CLAP_PATH_SIZE};
+
+/// The information a CLAP host asks for about each parameter. This mirrors
+/// `clap_param_info`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClapParamInfo {
+    /// The parameter's stable id. This is the parameter's table index.
+    pub id: u32,
+    pub name: String,
+    /// The group the parameter is shown in, from the table's `group` column.
+    pub module: String,
+    pub min_value: f64,
+    pub max_value: f64,
+    pub default_value: f64,
+    pub is_automatable: bool,
+    /// Stepped parameters count up by one per step, from the table's `min`.
+    pub is_stepped: bool,
+    /// Readonly parameters, such as meters, ignore `set_param_value`.
+    pub is_readonly: bool,
+}
+
+/// CLAP's params extension, in terms of parameter ids. Values are CLAP's
+/// plain values: continuous parameters use the table's `min` to `max` range,
+/// and stepped parameters count up by one per step from `min`.
+pub trait ClapParams {
+    fn param_count(&self) -> u32;
+    /// `index` is a position from `0` to `param_count()`, not an id.
+    fn param_info(&self, index: u32) -> Option<ClapParamInfo>;
+    fn param_value(&self, id: u32) -> Option<f64>;
+    /// Sets the parameter from the host, without notifying the host back.
+    /// Returns false for unknown ids and readonly parameters.
+    fn set_param_value(&self, id: u32, value: f64) -> bool;
+    fn param_value_to_text(&self, id: u32, value: f64) -> Option<String>;
+    fn param_text_to_value(&self, id: u32, text: &str) -> Option<f64>;
+}
+
+/// A CLAP plugin whose parameters implement `ClapParams`.
+pub trait ClapPlugin {
+    type Params: ClapParams;
+
+    /// Returns the parameters of the plugin which `plugin` belongs to.
+    ///
+    /// # Safety
+    ///
+    /// `plugin` is a pointer the host passed to the params extension, so it
+    /// points to the `clap_plugin` of an instance of this plugin.
+    unsafe fn params<'a>(plugin: *const clap_plugin) -> &'a Self::Params;
+}
+
+/// Returns the params extension for `P`, to hand the host from the plugin's
+/// `get_extension` when it asks for `CLAP_EXT_PARAMS`. This is a `const fn`
+/// so the extension can be kept in a `static`.
+pub const fn params_extension<P: ClapPlugin>() -> clap_plugin_params {
+    clap_plugin_params {
+        count: Some(count::<P>),
+        get_info: Some(get_info::<P>),
+        get_value: Some(get_value::<P>),
+        value_to_text: Some(value_to_text::<P>),
+        text_to_value: Some(text_to_value::<P>),
+        flush: Some(flush::<P>),
+    }
+}
+
+unsafe extern "C" fn count<P: ClapPlugin>(plugin: *const clap_plugin) -> u32 {
+    P::params(plugin).param_count()
+}
+
+unsafe extern "C" fn get_info<P: ClapPlugin>(
+    plugin: *const clap_plugin,
+    param_index: u32,
+    param_info: *mut clap_param_info,
+) -> bool {
+    let info = match P::params(plugin).param_info(param_index) {
+        Some(info) => info,
+        None => return false,
+    };
+    let mut flags = 0;
+    if info.is_automatable {
+        flags |= CLAP_PARAM_IS_AUTOMATABLE;
+    }
+    if info.is_stepped {
+        flags |= CLAP_PARAM_IS_STEPPED;
+    }
+    if info.is_readonly {
+        flags |= CLAP_PARAM_IS_READONLY;
+    }
+    let mut out = clap_param_info {
+        id: info.id as clap_id,
+        flags,
+        cookie: std::ptr::null_mut(),
+        name: [0; CLAP_NAME_SIZE],
+        module: [0; CLAP_PATH_SIZE],
+        min_value: info.min_value,
+        max_value: info.max_value,
+        default_value: info.default_value,
+    };
+    copy_text(&info.name, &mut out.name);
+    copy_text(&info.module, &mut out.module);
+    param_info.write(out);
+    true
+}
+
+unsafe extern "C" fn get_value<P: ClapPlugin>(
+    plugin: *const clap_plugin,
+    param_id: clap_id,
+    out_value: *mut f64,
+) -> bool {
+    match P::params(plugin).param_value(param_id) {
+        Some(value) => {
+            out_value.write(value);
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn value_to_text<P: ClapPlugin>(
+    plugin: *const clap_plugin,
+    param_id: clap_id,
+    value: f64,
+    out_buffer: *mut c_char,
+    out_buffer_capacity: u32,
+) -> bool {
+    if out_buffer.is_null() || out_buffer_capacity == 0 {
+        return false;
+    }
+    match P::params(plugin).param_value_to_text(param_id, value) {
+        Some(text) => {
+            let buffer = std::slice::from_raw_parts_mut(out_buffer, out_buffer_capacity as usize);
+            copy_text(&text, buffer);
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn text_to_value<P: ClapPlugin>(
+    plugin: *const clap_plugin,
+    param_id: clap_id,
+    param_value_text: *const c_char,
+    out_value: *mut f64,
+) -> bool {
+    if param_value_text.is_null() {
+        return false;
+    }
+    let text = match CStr::from_ptr(param_value_text).to_str() {
+        Ok(text) => text,
+        Err(_) => return false,
+    };
+    match P::params(plugin).param_text_to_value(param_id, text) {
+        Some(value) => {
+            out_value.write(value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Applies the parameter changes in `in_`. The parameters aren't changed by
+/// anything but the host, so nothing is written to `out`.
+unsafe extern "C" fn flush<P: ClapPlugin>(
+    plugin: *const clap_plugin,
+    in_: *const clap_input_events,
+    _out: *const clap_output_events,
+) {
+    if in_.is_null() {
+        return;
+    }
+    let events = &*in_;
+    let (size, get) = match (events.size, events.get) {
+        (Some(size), Some(get)) => (size, get),
+        _ => return,
+    };
+    let params = P::params(plugin);
+    for index in 0..size(in_) {
+        let header = get(in_, index);
+        if header.is_null()
+            || (*header).space_id != CLAP_CORE_EVENT_SPACE_ID
+            || (*header).type_ != CLAP_EVENT_PARAM_VALUE
+        {
+            continue;
+        }
+        let event = &*(header as *const clap_event_param_value);
+        params.set_param_value(event.param_id, event.value);
+    }
+}
+
+/// Copies `text` into a NUL-terminated C string in `buffer`, cutting it short
+/// at a character boundary if it doesn't fit.
+fn copy_text(text: &str, buffer: &mut [c_char]) {
+    let mut length = text.len().min(buffer.len().saturating_sub(1));
+    while !text.is_char_boundary(length) {
+        length -= 1;
+    }
+    for (out, &byte) in buffer.iter_mut().zip(&text.as_bytes()[..length]) {
+        *out = byte as c_char;
+    }
+    if let Some(end) = buffer.get_mut(length) {
+        *end = 0;
+    }
+}
@@ -1,13 +1,1115 @@
+#[cfg(feature = "clap")]
+pub mod clap_adapter;
 pub mod macros;
+pub mod migration;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "serde")]
+pub mod preset;
+pub mod state;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "derive")]
+pub use vst_utils_derive::PluginParameters;
+
+pub use vst_utils_math::*;
+
+#[doc(hidden)]
+pub use paste;
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub use arbitrary;
+
+#[cfg(feature = "debug-params")]
+#[doc(hidden)]
+pub use log;
+
+/// The default cap on parameter indices. Hosts limit how many parameters a
+/// plugin may have to far below `i32::MAX`, so `impl_all!` fails to compile
+/// if a row's index isn't below this, which catches typos like `1000000`.
+/// Pass `max_params = N` to `impl_all!` to change it.
+pub const MAX_PARAMS: i32 = 2048;
+
+/// Storage for a single parameter's normalized value. The parameter fields of
+/// `$raw_parameters` may be any type implementing this, so the table macros
+/// don't depend on a particular atomic type. `vst::util::AtomicFloat`
+/// implements it when the `vst` feature is enabled (the default).
+pub trait ParameterStore: Send + Sync {
+    fn new(value: f32) -> Self
+    where
+        Self: Sized;
+    fn get(&self) -> f32;
+    fn set(&self, value: f32);
+}
+
+#[cfg(feature = "vst")]
+impl ParameterStore for vst::util::AtomicFloat {
+    fn new(value: f32) -> Self {
+        vst::util::AtomicFloat::new(value)
+    }
+
+    fn get(&self) -> f32 {
+        vst::util::AtomicFloat::get(self)
+    }
+
+    fn set(&self, value: f32) {
+        vst::util::AtomicFloat::set(self, value)
+    }
+}
+
+impl ParameterStore for std::sync::atomic::AtomicBool {
+    fn new(value: f32) -> Self {
+        std::sync::atomic::AtomicBool::new(value >= 0.5)
+    }
+
+    fn get(&self) -> f32 {
+        if self.load(std::sync::atomic::Ordering::Relaxed) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn set(&self, value: f32) {
+        self.store(value >= 0.5, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Stores the plain integer value, which `ParameterKind::Int` converts to
+/// and from a normalized value using the parameter's step count.
+impl ParameterStore for std::sync::atomic::AtomicI32 {
+    fn new(value: f32) -> Self {
+        std::sync::atomic::AtomicI32::new(value.round() as i32)
+    }
+
+    fn get(&self) -> f32 {
+        self.load(std::sync::atomic::Ordering::Relaxed) as f32
+    }
+
+    fn set(&self, value: f32) {
+        self.store(value.round() as i32, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// What a parameter's field actually stores, from the table's `kind` column.
+/// The host always sees a normalized `[0, 1]` value regardless of the kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    /// A normalized value, ex: in a `vst::util::AtomicFloat`.
+    Float,
+    /// An on/off value, ex: in an `AtomicBool`. Normalized values round at 0.5.
+    Bool,
+    /// A step from `0` to `steps - 1`, ex: in an `AtomicI32`. Requires the
+    /// row's `steps` column.
+    Int,
+}
+
+impl ParameterKind {
+    /// Converts a value read from a `ParameterStore` into a normalized value.
+    pub fn to_normalized(self, stored: f32, steps: i32) -> f32 {
+        match self {
+            ParameterKind::Float => stored,
+            ParameterKind::Bool => {
+                if stored >= 0.5 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            ParameterKind::Int if steps > 1 => stored / (steps - 1) as f32,
+            ParameterKind::Int => 0.0,
+        }
+    }
+
+    /// Converts a normalized value into the value to write to a `ParameterStore`.
+    pub fn from_normalized(self, value: f32, steps: i32) -> f32 {
+        match self {
+            ParameterKind::Float => value,
+            ParameterKind::Bool => {
+                if value >= 0.5 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            ParameterKind::Int if steps > 1 => (value * (steps - 1) as f32)
+                .round()
+                .clamp(0.0, (steps - 1) as f32),
+            ParameterKind::Int => 0.0,
+        }
+    }
+}
+
+/// How a parameter's normalized value maps onto its `min`/`max` range, from
+/// the table's `curve` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    /// `lerp(min, max, x)`.
+    Linear,
+    /// `lerp(min, max, ease_in_expo(x))`, for values which need more
+    /// resolution near `min`.
+    Exp,
+    /// `min * (max / min)^x`, for frequency-like values. Requires `min` and
+    /// `max` to be above zero, and falls back to `Linear` otherwise.
+    Log,
+}
+
+impl Curve {
+    /// Maps a normalized value into `[min, max]`.
+    pub fn denormalize(self, min: f32, max: f32, x: f32) -> f32 {
+        match self {
+            Curve::Log if min > 0.0 && max > 0.0 => min * (max / min).powf(x),
+            Curve::Exp => lerp(min, max, ease_in_expo(x)),
+            _ => lerp(min, max, x),
+        }
+    }
+
+    /// Maps a value in `[min, max]` back to a normalized value.
+    pub fn normalize(self, min: f32, max: f32, value: f32) -> f32 {
+        match self {
+            Curve::Log if min > 0.0 && max > 0.0 && value > 0.0 => {
+                inv_lerp(min.ln(), max.ln(), value.ln())
+            }
+            Curve::Log if min > 0.0 && max > 0.0 => 0.0,
+            Curve::Exp => ease_in_expo_inv(inv_lerp(min, max, value)),
+            _ => inv_lerp(min, max, value),
+        }
+    }
+}
+
+/// The error returned when converting an out of range index into a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParameterIndexError(pub i32);
+
+impl std::fmt::Display for ParameterIndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no parameter has index {}", self.0)
+    }
+}
+
+impl std::error::Error for ParameterIndexError {}
+
+/// The error returned when converting a name which matches no parameter's
+/// name or aliases into a parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterNameError(pub String);
+
+impl std::fmt::Display for ParameterNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no parameter is named {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParameterNameError {}
+
+/// Brackets a knob drag with `begin_edit` and `end_edit`, as returned by the
+/// generated `gesture` function. `end_edit` is sent when the guard is dropped,
+/// so it fires even if the drag ends with an early return or a panic.
+#[cfg(feature = "vst")]
+pub struct ParamGesture<'a, R, P: Copy> {
+    host: vst::plugin::HostCallback,
+    index: i32,
+    params: &'a R,
+    parameter: P,
+    set: fn(&R, f32, P),
+}
+
+#[cfg(feature = "vst")]
+impl<'a, R, P: Copy> ParamGesture<'a, R, P> {
+    /// Sends `begin_edit` for `index` and returns the guard. `set` stores a
+    /// value without notifying the host, such as `set_without_notify`.
+    pub fn new(
+        host: vst::plugin::HostCallback,
+        index: i32,
+        params: &'a R,
+        parameter: P,
+        set: fn(&R, f32, P),
+    ) -> ParamGesture<'a, R, P> {
+        use vst::host::Host;
+        host.begin_edit(index);
+        ParamGesture {
+            host,
+            index,
+            params,
+            parameter,
+            set,
+        }
+    }
+
+    /// Sets the parameter's normalized value without sending another edit.
+    pub fn set(&self, value: f32) {
+        (self.set)(self.params, value, self.parameter);
+    }
+}
+
+#[cfg(feature = "vst")]
+impl<R, P: Copy> Drop for ParamGesture<'_, R, P> {
+    fn drop(&mut self) {
+        use vst::host::Host;
+        self.host.end_edit(self.index);
+    }
+}
+
+/// A row of the parameter table, as returned by the generated `descriptors`.
+/// This is enough to build a widget for each parameter, such as a slider from
+/// `min` to `max` with `steps` notches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParameterDescriptor {
+    pub index: i32,
+    pub name: &'static str,
+    pub default: f32,
+    pub min: f32,
+    pub max: f32,
+    pub unit: &'static str,
+    pub steps: i32,
+    pub kind: ParameterKind,
+}
+
+/// A `fn(&T, P)` which is called whenever a parameter of `T` changes. This
+/// holds the pointer in an atomic, so it can be swapped out and called from
+/// any thread without locking. This backs the generated `set_on_change` when
+/// `impl_all!` is passed `on_change`, and must be in a field named `on_change`.
+pub struct ChangeCallback<T, P> {
+    callback: std::sync::atomic::AtomicUsize,
+    marker: std::marker::PhantomData<fn(&T, P)>,
+}
+
+impl<T, P> ChangeCallback<T, P> {
+    pub const fn new() -> ChangeCallback<T, P> {
+        ChangeCallback {
+            callback: std::sync::atomic::AtomicUsize::new(0),
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Replaces the callback, or removes it if `callback` is None.
+    pub fn set(&self, callback: Option<fn(&T, P)>) {
+        let callback = callback.map_or(0, |callback| callback as usize);
+        self.callback
+            .store(callback, std::sync::atomic::Ordering::Release);
+    }
+
+    /// Calls the callback, if there is one.
+    pub fn call(&self, raw_parameters: &T, parameter: P) {
+        let callback = self.callback.load(std::sync::atomic::Ordering::Acquire);
+        if callback != 0 {
+            // Safety: the only nonzero values stored are `fn(&T, P)` pointers.
+            let callback: fn(&T, P) = unsafe { std::mem::transmute(callback) };
+            callback(raw_parameters, parameter);
+        }
+    }
+}
+
+impl<T, P> Default for ChangeCallback<T, P> {
+    fn default() -> Self {
+        ChangeCallback::new()
+    }
+}
+
+/// One bit per parameter index, set when the parameter is written. This backs
+/// the generated `take_dirty` when `impl_all!` is passed `track_dirty`, and
+/// must be in a field named `dirty`. Only indices below 64 fit, which
+/// `track_dirty` checks at compile time.
+#[derive(Debug, Default)]
+pub struct DirtyFlags(std::sync::atomic::AtomicU64);
+
+impl DirtyFlags {
+    pub const fn new() -> DirtyFlags {
+        DirtyFlags(std::sync::atomic::AtomicU64::new(0))
+    }
+
+    /// Sets the bit for `index`. Indices outside `0..64` are ignored.
+    pub fn mark(&self, index: i32) {
+        if (0..64).contains(&index) {
+            self.0
+                .fetch_or(1 << index, std::sync::atomic::Ordering::AcqRel);
+        }
+    }
+
+    /// Returns the bits set since the last call, and clears them.
+    pub fn take(&self) -> u64 {
+        self.0.swap(0, std::sync::atomic::Ordering::AcqRel)
+    }
+}
+
+/// A counter per parameter, bumped whenever the parameter is written, even to
+/// the same value. This backs the generated `generation` when `impl_all!` is
+/// passed `track_generations`, and must be in a field named `generations`.
+#[derive(Debug)]
+pub struct Generations(Vec<std::sync::atomic::AtomicU32>);
+
+impl Generations {
+    /// Creates counters for parameters with indices `0..count`.
+    pub fn new(count: usize) -> Generations {
+        Generations((0..count).map(|_| Default::default()).collect())
+    }
+
+    /// Bumps the counter for `index`. Indices out of range are ignored.
+    pub fn bump(&self, index: i32) {
+        if let Some(generation) = self.0.get(index as usize) {
+            generation.fetch_add(1, std::sync::atomic::Ordering::AcqRel);
+        }
+    }
+
+    /// Returns the counter for `index`, or 0 if it is out of range.
+    pub fn get(&self, index: i32) -> u32 {
+        self.0.get(index as usize).map_or(0, |generation| {
+            generation.load(std::sync::atomic::Ordering::Acquire)
+        })
+    }
+}
+
+/// Per-parameter touch state, for hosts which expect one `begin_edit` and
+/// `end_edit` around a whole automation pass. This backs the generated
+/// `touch` and `untouch` when `impl_all!` is passed `track_touch`, and must be
+/// in a field named `touch`.
+#[derive(Debug)]
+pub struct TouchFlags {
+    touched: Vec<std::sync::atomic::AtomicBool>,
+    editing: Vec<std::sync::atomic::AtomicBool>,
+}
+
+impl TouchFlags {
+    /// Creates flags for parameters with indices `0..count`.
+    pub fn new(count: usize) -> TouchFlags {
+        TouchFlags {
+            touched: (0..count).map(|_| Default::default()).collect(),
+            editing: (0..count).map(|_| Default::default()).collect(),
+        }
+    }
+
+    /// Marks the parameter as touched. Indices out of range are ignored.
+    pub fn touch(&self, index: i32) {
+        if let Some(touched) = self.touched.get(index as usize) {
+            touched.store(true, std::sync::atomic::Ordering::Release);
+        }
+    }
+
+    /// Returns None if the parameter isn't touched. Otherwise, returns whether
+    /// this is the first write since it was touched, in which case the caller
+    /// should begin an edit.
+    pub fn write(&self, index: i32) -> Option<bool> {
+        let index = index as usize;
+        let touched = self.touched.get(index)?;
+        if !touched.load(std::sync::atomic::Ordering::Acquire) {
+            return None;
+        }
+        Some(!self.editing[index].swap(true, std::sync::atomic::Ordering::AcqRel))
+    }
+
+    /// Clears the parameter's touch, and returns whether it was written since
+    /// it was touched, in which case the caller should end the edit.
+    pub fn untouch(&self, index: i32) -> bool {
+        let index = index as usize;
+        match self.touched.get(index) {
+            Some(touched) => {
+                touched.store(false, std::sync::atomic::Ordering::Release);
+                self.editing[index].swap(false, std::sync::atomic::Ordering::AcqRel)
+            }
+            None => false,
+        }
+    }
+}
+
+/// The shortest time, in milliseconds, between the edits `set` sends the host
+/// for one parameter when `impl_all!` is passed `throttle_edits`. Some hosts
+/// send thousands of near-identical values per second while playing back
+/// automation, and recording an edit for each bloats the automation lane.
+pub const EDIT_THROTTLE_MS: u64 = 50;
+
+/// When each parameter last sent the host an edit. This backs the throttling
+/// of `set` when `impl_all!` is passed `throttle_edits`, and must be in a
+/// field named `throttle`.
+#[derive(Debug)]
+pub struct EditThrottle {
+    start: std::time::Instant,
+    /// Milliseconds after `start`, plus one, or zero if there was no edit yet.
+    last_edit: Vec<std::sync::atomic::AtomicU64>,
+}
+
+impl EditThrottle {
+    /// Creates a throttle for parameters with indices `0..count`.
+    pub fn new(count: usize) -> EditThrottle {
+        EditThrottle {
+            start: std::time::Instant::now(),
+            last_edit: (0..count).map(|_| Default::default()).collect(),
+        }
+    }
+
+    /// Returns whether the parameter may send an edit now, which is when it
+    /// hasn't sent one in the last `EDIT_THROTTLE_MS`. If so, records that it
+    /// did. Indices out of range are never throttled.
+    pub fn allow(&self, index: i32) -> bool {
+        use std::sync::atomic::Ordering;
+        let last_edit = match self.last_edit.get(index as usize) {
+            Some(last_edit) => last_edit,
+            None => return true,
+        };
+        let now = self.start.elapsed().as_millis() as u64 + 1;
+        let last = last_edit.load(Ordering::Acquire);
+        if last != 0 && now.saturating_sub(last) < EDIT_THROTTLE_MS {
+            return false;
+        }
+        last_edit
+            .compare_exchange(last, now, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+}
+
+/// A parameter's value along with its formatted text, as returned by
+/// `get_parameter_value`. `value` is the denormalized value, in the row's
+/// `min`/`max` range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterValue {
+    pub value: f32,
+    pub text: String,
+    pub unit: String,
+}
+
+/// A `$string` formatter from a parameter table. This is implemented both for
+/// functions which take just the parameter's value, and for functions which
+/// also take the whole `Parameters` struct, for displays that depend on other
+/// parameters. `Marker` is only there to keep the two implementations apart.
+pub trait Formatter<T, P, Marker> {
+    fn format(&self, value: T, parameters: &P) -> (String, String);
+}
+
+#[doc(hidden)]
+pub struct ValueOnly;
+
+#[doc(hidden)]
+pub struct WithParameters;
+
+impl<T, P, F: Fn(T) -> (String, String)> Formatter<T, P, ValueOnly> for F {
+    fn format(&self, value: T, _parameters: &P) -> (String, String) {
+        self(value)
+    }
+}
+
+impl<T, P, F: Fn(T, &P) -> (String, String)> Formatter<T, P, WithParameters> for F {
+    fn format(&self, value: T, parameters: &P) -> (String, String) {
+        self(value, parameters)
+    }
+}
+
+/// Clamps a `Parameters` field into its row's `min`/`max` range before it is
+/// formatted, so a corrupt value (ex: from a bad preset) can't display as
+/// nonsense like "4.2e38 dB". Only rows with a `min` or `max` column are
+/// clamped. This is implemented for `f32` and `f64`, and must be implemented
+/// for any other field type whose row has a range.
+pub trait DisplayClamp {
+    fn display_clamp(self, min: f32, max: f32) -> Self;
+}
+
+impl DisplayClamp for f32 {
+    fn display_clamp(self, min: f32, max: f32) -> f32 {
+        if self.is_nan() {
+            min
+        } else {
+            self.clamp(min.min(max), max.max(min))
+        }
+    }
+}
+
+impl DisplayClamp for f64 {
+    fn display_clamp(self, min: f32, max: f32) -> f64 {
+        (self as f32).display_clamp(min, max) as f64
+    }
+}
+
+/// Rounds a `Parameters` field to the nearest multiple of its row's
+/// `display_step` before it is formatted, so hosts don't show the last digit
+/// flickering. Only rows with a `display_step` column are rounded. This is
+/// implemented for `f32` and `f64`.
+pub trait DisplayStep {
+    fn display_step(self, step: f32) -> Self;
+}
+
+impl DisplayStep for f32 {
+    fn display_step(self, step: f32) -> f32 {
+        if step > 0.0 {
+            (self / step).round() * step
+        } else {
+            self
+        }
+    }
+}
+
+impl DisplayStep for f64 {
+    fn display_step(self, step: f32) -> f64 {
+        let step = step as f64;
+        if step > 0.0 {
+            (self / step).round() * step
+        } else {
+            self
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn format_parameter<T, P, Marker>(
+    formatter: impl Formatter<T, P, Marker>,
+    value: T,
+    parameters: &P,
+) -> (String, String) {
+    formatter.format(value, parameters)
+}
+
+/// FNV-1a hash of `name`, ignoring ASCII case.
+#[doc(hidden)]
+pub const fn name_hash(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i].to_ascii_lowercase() as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Returns one more than the largest of `indices`, or 0 if there are none.
+#[doc(hidden)]
+pub const fn index_limit(indices: &[i32]) -> i32 {
+    let mut limit = 0;
+    let mut i = 0;
+    while i < indices.len() {
+        if indices[i] >= limit {
+            limit = indices[i] + 1;
+        }
+        i += 1;
+    }
+    limit
+}
+
+/// Pairs each name's hash with its position in `names`, sorted by hash, so
+/// `find_name` can binary search it.
+#[doc(hidden)]
+pub const fn sorted_name_hashes<const N: usize>(names: [&str; N]) -> [(u64, usize); N] {
+    let mut sorted = [(0, 0); N];
+    let mut i = 0;
+    while i < N {
+        sorted[i] = (name_hash(names[i]), i);
+        let mut j = i;
+        while j > 0 && sorted[j - 1].0 > sorted[j].0 {
+            let swap = sorted[j - 1];
+            sorted[j - 1] = sorted[j];
+            sorted[j] = swap;
+            j -= 1;
+        }
+        i += 1;
+    }
+    sorted
+}
+
+/// Looks up `name` in a table from `sorted_name_hashes`, returning the index
+/// from the matching row of `meta`. Rows whose hash collides are told apart
+/// by comparing their names.
+#[doc(hidden)]
+pub fn find_name(sorted: &[(u64, usize)], meta: &[(i32, &str, f32)], name: &str) -> Option<i32> {
+    let hash = name_hash(name);
+    let start = sorted.partition_point(|&(row_hash, _)| row_hash < hash);
+    sorted[start..]
+        .iter()
+        .take_while(|&&(row_hash, _)| row_hash == hash)
+        .map(|&(_, row)| meta[row])
+        .find(|(_, row_name, _)| row_name.eq_ignore_ascii_case(name))
+        .map(|(index, _, _)| index)
+}
+
+/// Parses the number at the start of `text`, ignoring any units after it
+/// (ex: "-6.5 dB" gives -6.5, and "1e3 Hz" gives 1000). Meant for building
+/// `parse` columns. Text which continues the number in a way that can't be
+/// parsed, such as "1.2.3" or "1e", gives `None` rather than a prefix of it.
+pub fn parse_number(text: &str) -> Option<f32> {
+    let text = text.trim();
+    let bytes = text.as_bytes();
+    let is_sign = |i: usize| matches!(bytes.get(i), Some(b'+') | Some(b'-'));
+    let is_digit = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut end = if is_sign(0) { 1 } else { 0 };
+    while is_digit(end) || bytes.get(end) == Some(&b'.') {
+        end += 1;
+    }
+    if matches!(bytes.get(end), Some(b'e') | Some(b'E')) {
+        let mut exponent_end = if is_sign(end + 1) { end + 2 } else { end + 1 };
+        while is_digit(exponent_end) {
+            exponent_end += 1;
+        }
+        if is_digit(exponent_end - 1) {
+            end = exponent_end;
+        }
+    }
+    match bytes.get(end) {
+        Some(b'.') | Some(b'+') | Some(b'-') | Some(b'e') | Some(b'E') => None,
+        _ => text[..end].parse().ok(),
+    }
+}
+
+/// The text every `make_strings` formatter shows for a NaN or infinite value,
+/// which DSP code can briefly produce. Hosts show "NaN" and "inf" badly, if
+/// at all.
+pub const NON_FINITE_TEXT: &str = "—";
 
 pub fn make_strings(value: f32, label: &str) -> (String, String) {
-    (format!("{:.2}", value), label.to_string())
+    make_strings_prec(value, label, 2)
+}
+
+pub fn make_strings_prec(value: f32, label: &str, precision: usize) -> (String, String) {
+    if !value.is_finite() {
+        return (NON_FINITE_TEXT.to_string(), label.to_string());
+    }
+    (format!("{:.*}", precision, value), label.to_string())
+}
+
+/// Formats `value` to `sig_figs` significant figures rather than a fixed
+/// number of decimals, ex: 0.00123 reads "0.0012" and 12345 reads "12000"
+/// with 2 significant figures.
+pub fn make_strings_sig(value: f32, sig_figs: usize, label: &str) -> (String, String) {
+    if value == 0.0 || !value.is_finite() {
+        return make_strings_prec(value, label, sig_figs.saturating_sub(1));
+    }
+    let sig_figs = sig_figs.max(1) as i32;
+    let decimals = |value: f64| sig_figs - 1 - value.abs().log10().floor() as i32;
+    // Rounding can carry into the next power of ten (ex: 9.96 to 10), which
+    // then needs one less decimal.
+    let value = value as f64;
+    let scale = 10.0f64.powi(decimals(value));
+    let rounded = (value * scale).round() / scale;
+    let decimals = decimals(rounded).max(0) as usize;
+    make_strings_prec(rounded as f32, label, decimals)
+}
+
+pub fn make_strings_db(amp: f32) -> (String, String) {
+    if !amp.is_finite() {
+        (NON_FINITE_TEXT.to_string(), "dB".to_string())
+    } else if amp <= 0.0 {
+        ("-inf".to_string(), "dB".to_string())
+    } else {
+        make_strings_prec(linear_to_db(amp), "dB", 1)
+    }
+}
+
+pub fn make_strings_percent(value: f32) -> (String, String) {
+    make_strings_prec((value * 100.0).round(), "%", 0)
+}
+
+pub fn make_strings_hz(hz: f32) -> (String, String) {
+    if hz >= 1000.0 && hz.is_finite() {
+        make_strings_prec(hz / 1000.0, "kHz", 2)
+    } else {
+        make_strings_prec(hz, "Hz", 0)
+    }
+}
+
+pub fn make_strings_time(seconds: f32) -> (String, String) {
+    if seconds >= 1.0 || !seconds.is_finite() {
+        make_strings_prec(seconds, "s", 2)
+    } else {
+        make_strings_prec(seconds * 1000.0, "ms", 0)
+    }
+}
+
+/// Formats a time knob whose value was stored as `ease_in_expo(x)`, by undoing
+/// the easing and mapping `x` onto `min_s..max_s` seconds.
+pub fn make_strings_eased_time(stored: f32, min_s: f32, max_s: f32) -> (String, String) {
+    make_strings_time(lerp(min_s, max_s, ease_in_expo_inv(stored)))
+}
+
+/// Formats a frequency as the nearest note and its offset from it in cents,
+/// ex: `("A4", "+0 ct")` for 440 Hz. Notes from C-1 up to B10 are named, which
+/// covers the audible range to past 20 kHz. Other frequencies, and ones which
+/// aren't finite, are formatted as Hz instead.
+pub fn make_strings_note(hz: f32) -> (String, String) {
+    const NAMES: [&str; 12] = [
+        "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+    ];
+    let note = 69.0 + 12.0 * (hz / 440.0).log2();
+    let nearest = note.round();
+    if !(0.0..=143.0).contains(&nearest) {
+        return make_strings_hz(hz);
+    }
+    let cents = ((note - nearest) * 100.0).round() as i32;
+    let nearest = nearest as usize;
+    let octave = nearest as i32 / 12 - 1;
+    (
+        format!("{}{}", NAMES[nearest % 12], octave),
+        format!("{:+} ct", cents),
+    )
+}
+
+/// Formats a compressor ratio, ex: `("4.0:1", "")`. Ratios of 100 and above
+/// read "∞:1", since they act as a limiter.
+pub fn make_strings_ratio(ratio: f32) -> (String, String) {
+    if ratio >= 100.0 {
+        ("∞:1".to_string(), "".to_string())
+    } else if !ratio.is_finite() {
+        (NON_FINITE_TEXT.to_string(), "".to_string())
+    } else {
+        (format!("{:.1}:1", ratio), "".to_string())
+    }
+}
+
+/// Formats a normalized pan position, where 0.0 is full left, 0.5 is center
+/// and 1.0 is full right, ex: "L50", "C" or "R100".
+pub fn make_strings_pan(norm: f32) -> (String, String) {
+    if !norm.is_finite() {
+        return (NON_FINITE_TEXT.to_string(), "".to_string());
+    }
+    let amount = ((norm - 0.5) * 200.0).round() as i32;
+    let text = match amount {
+        0 => "C".to_string(),
+        amount if amount < 0 => format!("L{}", -amount),
+        amount => format!("R{}", amount),
+    };
+    (text, "".to_string())
+}
+
+/// Formats a toggle parameter. Values of exactly 0.5 and above count as "On".
+pub fn make_strings_bool(value: f32) -> (String, String) {
+    if !value.is_finite() {
+        (NON_FINITE_TEXT.to_string(), "".to_string())
+    } else if value >= 0.5 {
+        ("On".to_string(), "".to_string())
+    } else {
+        ("Off".to_string(), "".to_string())
+    }
 }
 
-pub fn ease_in_expo(x: f32) -> f32 {
-    if x <= 0.0 {
-        0.0
+/// Marks up formatted `text` for a bipolar parameter, such as pan or detune,
+/// whose normalized center of 0.5 is zero. Values at the center read exactly
+/// "0" and values above it get a leading "+". Used for rows with
+/// `bipolar = true`.
+#[doc(hidden)]
+pub fn bipolar_text(text: String, normalized: f32) -> String {
+    if (normalized - 0.5).abs() < 1.0e-6 {
+        "0".to_string()
+    } else if normalized > 0.5 && !text.starts_with('+') {
+        format!("+{}", text)
     } else {
-        (2.0f32.powf(10.0 * x) - 1.0) / (2.0f32.powf(10.0) - 1.0)
+        text
+    }
+}
+
+/// Returns an arbitrary normalized value in `[0, 1]`, for fuzzing.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_normalized(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<f32> {
+    Ok(u.int_in_range(0..=u32::MAX)? as f32 / u32::MAX as f32)
+}
+
+/// The sample rate assumed until the host calls `set_sample_rate`.
+pub const DEFAULT_SAMPLE_RATE: f32 = 44100.0;
+
+/// One-pole smoothing for a parameter value, to avoid zipper noise when a
+/// parameter changes quickly. Call `next` once per sample in the audio callback.
+///
+/// The smoothing time is the time constant of the filter: after
+/// `smoothing_ms`, the value has moved about 63% of the way to its target.
+/// The per-sample coefficient is `exp(-1 / (smoothing_ms * 0.001 * sample_rate))`,
+/// so it must be recomputed with `set_sample_rate` whenever the host changes
+/// the sample rate, or the smoothing time will be wrong.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothedParam {
+    target: f32,
+    current: f32,
+    sample_rate: f32,
+    smoothing_ms: f32,
+    /// How much of the previous value is kept each sample, between 0 and 1.
+    coefficient: f32,
+}
+
+impl SmoothedParam {
+    pub fn new(sample_rate: f32, smoothing_ms: f32) -> SmoothedParam {
+        SmoothedParam {
+            target: 0.0,
+            current: 0.0,
+            sample_rate,
+            smoothing_ms,
+            coefficient: SmoothedParam::coefficient(sample_rate, smoothing_ms),
+        }
+    }
+
+    fn coefficient(sample_rate: f32, smoothing_ms: f32) -> f32 {
+        if smoothing_ms <= 0.0 || sample_rate <= 0.0 {
+            0.0
+        } else {
+            (-1.0 / (smoothing_ms * 0.001 * sample_rate)).exp()
+        }
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+        self.coefficient = SmoothedParam::coefficient(sample_rate, self.smoothing_ms);
+    }
+
+    /// Like `set_sample_rate`, but only recomputes the coefficient if the
+    /// sample rate changed, so it is cheap enough to call every block with
+    /// the rate stored by the generated `set_sample_rate`.
+    pub fn sync_sample_rate(&mut self, sample_rate: f32) {
+        #[allow(clippy::float_cmp)]
+        if sample_rate != self.sample_rate {
+            self.set_sample_rate(sample_rate);
+        }
+    }
+
+    pub fn set_target(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Jumps straight to `value` without smoothing.
+    pub fn set_immediate(&mut self, value: f32) {
+        self.target = value;
+        self.current = value;
+    }
+
+    /// Advances the smoothing by one sample and returns the new value.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> f32 {
+        self.current = self.target + self.coefficient * (self.current - self.target);
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_strings_prec_uses_the_given_decimals() {
+        assert_eq!(
+            make_strings_prec(12.34567, "st", 0),
+            ("12".to_string(), "st".to_string())
+        );
+        assert_eq!(make_strings_prec(12.34567, "ct", 4).0, "12.3457");
+        assert_eq!(make_strings(12.34567, "Hz").0, "12.35");
+    }
+
+    #[test]
+    fn make_strings_percent_rounds() {
+        assert_eq!(
+            make_strings_percent(0.333),
+            ("33".to_string(), "%".to_string())
+        );
+        assert_eq!(make_strings_percent(1.0).0, "100");
+        assert_eq!(make_strings_percent(0.0).0, "0");
+    }
+
+    #[test]
+    fn make_strings_hz_switches_to_khz_at_1000() {
+        assert_eq!(
+            make_strings_hz(440.0),
+            ("440".to_string(), "Hz".to_string())
+        );
+        assert_eq!(make_strings_hz(999.0).1, "Hz");
+        assert_eq!(
+            make_strings_hz(1000.0),
+            ("1.00".to_string(), "kHz".to_string())
+        );
+        assert_eq!(make_strings_hz(2500.0).0, "2.50");
+    }
+
+    #[test]
+    fn make_strings_time_switches_to_seconds_at_1() {
+        assert_eq!(
+            make_strings_time(1.0),
+            ("1.00".to_string(), "s".to_string())
+        );
+        assert_eq!(
+            make_strings_time(0.25),
+            ("250".to_string(), "ms".to_string())
+        );
+        assert_eq!(
+            make_strings_time(0.0004),
+            ("0".to_string(), "ms".to_string())
+        );
+        assert_eq!(make_strings_time(1.5).0, "1.50");
+    }
+
+    #[test]
+    fn make_strings_bool_counts_half_as_on() {
+        assert_eq!(make_strings_bool(0.5), ("On".to_string(), "".to_string()));
+        assert_eq!(make_strings_bool(0.49).0, "Off");
+        assert_eq!(make_strings_bool(1.0).0, "On");
+    }
+
+    #[test]
+    fn smoothed_param_converges() {
+        let mut param = SmoothedParam::new(44100.0, 10.0);
+        param.set_target(1.0);
+        let mut last = 0.0;
+        for _ in 0..4410 {
+            let value = param.next();
+            assert!(value >= last && value <= 1.0);
+            last = value;
+        }
+        assert!((last - 1.0).abs() < 1.0e-3);
+
+        param.set_immediate(0.25);
+        assert_eq!(param.next(), 0.25);
+    }
+
+    #[test]
+    fn smoothing_time_is_independent_of_sample_rate() {
+        // After one time constant, the value is 1 - 1/e of the way there.
+        fn after_time_constant(sample_rate: f32) -> f32 {
+            let mut param = SmoothedParam::new(sample_rate, 10.0);
+            param.set_target(1.0);
+            let samples = (sample_rate * 0.01) as usize;
+            (0..samples).fold(0.0, |_, _| param.next())
+        }
+        let expected = 1.0 - (-1.0f32).exp();
+        assert!((after_time_constant(44100.0) - expected).abs() < 1.0e-3);
+        assert!((after_time_constant(88200.0) - expected).abs() < 1.0e-3);
+
+        let mut param = SmoothedParam::new(44100.0, 10.0);
+        param.set_sample_rate(88200.0);
+        assert_eq!(param, SmoothedParam::new(88200.0, 10.0));
+    }
+
+    #[test]
+    fn parse_number_ignores_units() {
+        assert_eq!(parse_number("-6.5 dB"), Some(-6.5));
+        assert_eq!(parse_number("  440Hz "), Some(440.0));
+        assert_eq!(parse_number("+3"), Some(3.0));
+        assert_eq!(parse_number(".5"), Some(0.5));
+        assert_eq!(parse_number("1e3 Hz"), Some(1000.0));
+        assert_eq!(parse_number("2.5E-1"), Some(0.25));
+        assert_eq!(parse_number("1e"), None);
+        assert_eq!(parse_number("1e+ Hz"), None);
+        assert_eq!(parse_number("1-2"), None);
+        assert_eq!(parse_number("1.2.3"), None);
+        assert_eq!(parse_number("dB"), None);
+        assert_eq!(parse_number(""), None);
+    }
+
+    #[test]
+    fn bool_and_int_kinds_convert_normalized_values() {
+        assert_eq!(ParameterKind::Bool.from_normalized(0.49, 0), 0.0);
+        assert_eq!(ParameterKind::Bool.from_normalized(0.5, 0), 1.0);
+        assert_eq!(ParameterKind::Bool.to_normalized(1.0, 0), 1.0);
+        assert_eq!(ParameterKind::Int.from_normalized(0.7, 4), 2.0);
+        assert_eq!(ParameterKind::Int.to_normalized(3.0, 4), 1.0);
+        assert_eq!(ParameterKind::Float.from_normalized(0.3, 4), 0.3);
+
+        let stored = <std::sync::atomic::AtomicBool as ParameterStore>::new(0.75);
+        assert_eq!(ParameterStore::get(&stored), 1.0);
+        ParameterStore::set(&stored, 0.25);
+        assert_eq!(ParameterStore::get(&stored), 0.0);
+    }
+
+    #[test]
+    fn curves_round_trip() {
+        for curve in [Curve::Linear, Curve::Exp, Curve::Log] {
+            for i in 0..=100 {
+                let x = i as f32 / 100.0;
+                let value = curve.denormalize(20.0, 20000.0, x);
+                assert!(
+                    (curve.normalize(20.0, 20000.0, value) - x).abs() < 1.0e-4,
+                    "{:?} {}",
+                    curve,
+                    x
+                );
+            }
+        }
+        assert!((Curve::Log.denormalize(20.0, 20000.0, 0.5) - 632.4555).abs() < 1.0e-2);
+        assert_eq!(Curve::Log.denormalize(0.0, 1.0, 0.5), 0.5);
+    }
+
+    #[test]
+    fn make_strings_eased_time_endpoints() {
+        assert_eq!(
+            make_strings_eased_time(0.0, 0.01, 2.0),
+            ("10".to_string(), "ms".to_string())
+        );
+        assert_eq!(
+            make_strings_eased_time(1.0, 0.01, 2.0),
+            ("2.00".to_string(), "s".to_string())
+        );
+        assert_eq!(
+            make_strings_eased_time(ease_in_expo(0.5), 0.0, 2.0).0,
+            "1.00"
+        );
+    }
+
+    #[test]
+    fn make_strings_note_names_the_nearest_note() {
+        let note = |hz: f32| {
+            let (name, cents) = make_strings_note(hz);
+            format!("{} {}", name, cents)
+        };
+        assert_eq!(note(440.0), "A4 +0 ct");
+        assert_eq!(note(261.6256), "C4 +0 ct");
+        assert_eq!(note(466.1638), "A#4 +0 ct");
+        // 25 cents above A4.
+        assert_eq!(note(440.0 * 2f32.powf(25.0 / 1200.0)), "A4 +25 ct");
+        assert_eq!(note(435.0), "A4 -20 ct");
+        // High enough to cover the top of the audible range.
+        assert_eq!(note(20000.0), "D#10 +8 ct");
+        assert_eq!(note(8.1758), "C-1 +0 ct");
+
+        assert_eq!(make_strings_note(f32::NAN), make_strings_hz(f32::NAN));
+        assert_eq!(
+            make_strings_note(f32::INFINITY),
+            make_strings_hz(f32::INFINITY)
+        );
+        assert_eq!(make_strings_note(0.0), make_strings_hz(0.0));
+        assert_eq!(make_strings_note(-440.0), make_strings_hz(-440.0));
+        assert_eq!(make_strings_note(40000.0), make_strings_hz(40000.0));
+    }
+
+    #[test]
+    fn make_strings_ratio_limits_at_100() {
+        assert_eq!(
+            make_strings_ratio(2.5),
+            ("2.5:1".to_string(), "".to_string())
+        );
+        assert_eq!(make_strings_ratio(4.0).0, "4.0:1");
+        assert_eq!(make_strings_ratio(99.9).0, "99.9:1");
+        assert_eq!(make_strings_ratio(100.0).0, "∞:1");
+        assert_eq!(make_strings_ratio(f32::INFINITY).0, "∞:1");
+    }
+
+    #[test]
+    fn make_strings_pan_anchors() {
+        assert_eq!(make_strings_pan(0.0), ("L100".to_string(), "".to_string()));
+        assert_eq!(make_strings_pan(0.5).0, "C");
+        assert_eq!(make_strings_pan(1.0).0, "R100");
+        assert_eq!(make_strings_pan(0.25).0, "L50");
+        assert_eq!(make_strings_pan(0.501).0, "C");
+    }
+
+    #[test]
+    fn make_strings_sig_rounds_to_significant_figures() {
+        assert_eq!(make_strings_sig(0.00123, 2, "s").0, "0.0012");
+        assert_eq!(make_strings_sig(12345.0, 2, "Hz").0, "12000");
+        assert_eq!(make_strings_sig(1.5, 3, "").0, "1.50");
+        assert_eq!(make_strings_sig(-0.0456, 2, "").0, "-0.046");
+        assert_eq!(make_strings_sig(0.0, 3, "").0, "0.00");
+        assert_eq!(make_strings_sig(9.96, 2, "").0, "10");
+        assert_eq!(make_strings_sig(0.0999, 2, "").0, "0.10");
+        assert_eq!(make_strings_sig(99.5, 2, "").0, "100");
+    }
+
+    #[test]
+    fn formatters_show_placeholder_for_non_finite() {
+        for &value in &[f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(make_strings(value, "Hz").0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_db(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_percent(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_hz(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_time(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_pan(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_bool(value).0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_sig(value, 3, "").0, NON_FINITE_TEXT);
+            assert_eq!(make_strings_note(value).0, NON_FINITE_TEXT);
+        }
+        assert_eq!(make_strings_ratio(f32::NAN).0, NON_FINITE_TEXT);
+        assert_eq!(
+            make_strings_eased_time(f32::NAN, 0.001, 10.0).0,
+            NON_FINITE_TEXT
+        );
+        assert_eq!(make_strings_db(0.0).0, "-inf");
     }
 }
@@ -1,3 +1,180 @@
+/// Looks up an optional `name = value` column at the end of a table row,
+/// falling back to `$default` if the row doesn't set it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __table_column {
+    ($column:ident, $default:expr;) => {
+        $default
+    };
+    (automatable, $default:expr; automatable = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (min, $default:expr; min = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (max, $default:expr; max = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (steps, $default:expr; steps = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (group, $default:expr; group = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (short_name, $default:expr; short_name = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (display_name, $default:expr; display_name = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (parse, $default:expr; parse = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (bipolar, $default:expr; bipolar = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (aliases, $default:expr; aliases = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (mod_range, $default:expr; mod_range = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (cc, $default:expr; cc = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (readonly, $default:expr; readonly = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (display_step, $default:expr; display_step = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (unit, $default:expr; unit = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (curve, $default:expr; curve = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (kind, $default:expr; kind = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    (link, $default:expr; link = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $value
+    };
+    ($column:ident, $default:expr; $other:ident = $value:expr $(, $rest_column:ident = $rest_value:expr)*) => {
+        $crate::__table_column!($column, $default; $($rest_column = $rest_value),*)
+    };
+}
+
+/// Expands to `$then` for `impl_plugin_parameters! {@table ...}`, and to
+/// `$else` otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_table {
+    ([table] {$($then: tt)*} else {$($else: tt)*}) => {
+        $($then)*
+    };
+    ([] {$($then: tt)*} else {$($else: tt)*}) => {
+        $($else)*
+    };
+}
+
+/// Expands to `$then` when the `vst` feature is enabled, and to `$else`
+/// otherwise.
+#[cfg(feature = "vst")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_vst {
+    ({$($then: tt)*} else {$($else: tt)*}) => {
+        $($then)*
+    };
+}
+
+#[cfg(not(feature = "vst"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_vst {
+    ({$($then: tt)*} else {$($else: tt)*}) => {
+        $($else)*
+    };
+}
+
+/// Expands to `$then` if a table row sets the optional `$column`, and to
+/// `$else` otherwise. Unlike `__table_column!`, only the chosen block is
+/// compiled, so `$then` may use code which only makes sense for such rows.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_column {
+    ($column:ident; ; $then:block else $else:block) => {
+        $else
+    };
+    (min; min = $value:expr $(, $rest_column:ident = $rest_value:expr)*; $then:block else $else:block) => {
+        $then
+    };
+    (max; max = $value:expr $(, $rest_column:ident = $rest_value:expr)*; $then:block else $else:block) => {
+        $then
+    };
+    (display_step; display_step = $value:expr $(, $rest_column:ident = $rest_value:expr)*; $then:block else $else:block) => {
+        $then
+    };
+    ($column:ident; $other:ident = $value:expr $(, $rest_column:ident = $rest_value:expr)*; $then:block else $else:block) => {
+        $crate::__if_column!($column; $($rest_column = $rest_value),*; $then else $else)
+    };
+}
+
+/// Expands to `1` for any token, so `0 $(+ __count_row!($field_name))*`
+/// counts the rows of a table.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __count_row {
+    ($ignored:tt) => {
+        1
+    };
+}
+
+/// Warn about an out-of-range parameter index passed by the host. This does
+/// nothing unless the `debug-params` feature is enabled, in which case the
+/// warning goes through the `log` crate.
+#[cfg(feature = "debug-params")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __warn_bad_index {
+    ($function:expr, $index:expr) => {
+        $crate::log::warn!(
+            "{} called with out-of-range parameter index {}",
+            $function,
+            $index
+        )
+    };
+}
+
+#[cfg(not(feature = "debug-params"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __warn_bad_index {
+    ($function:expr, $index:expr) => {};
+}
+
+/// Sets a parameter from a value passed in by the host. With the
+/// `clamp-params` feature enabled the value is clamped to `[0, 1]` like
+/// `set_clamped`, otherwise it is stored as-is.
+#[cfg(feature = "clamp-params")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __set_from_host {
+    ($raw_parameters:expr, $value:expr, $parameter:expr) => {
+        $raw_parameters.set($value.clamp(0.0, 1.0), $parameter)
+    };
+}
+
+#[cfg(not(feature = "clamp-params"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __set_from_host {
+    ($raw_parameters:expr, $value:expr, $parameter:expr) => {
+        $raw_parameters.set($value, $parameter)
+    };
+}
+
 /// Implement PluginParameters for `$raw_parameters`. `$parameter_type` must
 /// be an enum which implements `TryFrom<i32>` and `Display`
 /// `$raw_parameters` must implement the following functions
@@ -9,9 +186,105 @@
 ///     returns a tuple where the first String is the parameter's name
 ///     (ex: "Master Volume") and the second tuple is the parameter's value
 ///     (ex: "12 db")
+///
+/// `impl_all!` invokes this as `impl_plugin_parameters! {@table ...}`, which
+/// also uses the table's columns, so `$raw_parameters` must then implement
+/// set_from_string(&self, $parameter_type, text: &str) -> bool
+///     parses user-entered text (ex: "6 dB") and sets the parameter to the
+///     matching normalized value. Returns false if the text could not be parsed
+/// is_automatable($parameter_type) -> bool
+///     returns whether the host may automate the given parameter
+/// is_readonly($parameter_type) -> bool
+///     returns whether the host's writes to the given parameter are ignored
+/// get_short_name($parameter_type) -> &'static str
+///     returns an abbreviated name for hosts with narrow parameter lists
+/// get_display_name($parameter_type) -> &'static str
+///     returns the name shown in the host's parameter list
+/// Without `@table`, every parameter is automatable, the host can't set
+/// parameters from text, there is no `get_parameter_short_name`, and the host
+/// is shown the parameter's `Display` text.
+///
+/// `set_parameter` ignores values within `echo_epsilon` of the current value
+/// (see below). This defaults to `0.0`, which only ignores exact echoes, since
+/// any larger dead-zone would also drop small but legitimate changes. Hosts
+/// which round-trip values through lower precision may need a larger one:
+/// `impl_plugin_parameters!(RawParameters, ParameterType, echo_epsilon = 0.001)`
+/// With the `clamp-params` feature, `set_parameter` clamps host values to
+/// `[0, 1]` before storing them.
+/// Requires the `vst` feature.
+#[cfg(feature = "vst")]
 #[macro_export]
 macro_rules! impl_plugin_parameters {
     ($raw_parameters: ident, $parameter_type: ident) => {
+        $crate::impl_plugin_parameters! {@impl [] $raw_parameters, $parameter_type, echo_epsilon = 0.0}
+    };
+    ($raw_parameters: ident, $parameter_type: ident, echo_epsilon = $echo_epsilon: expr) => {
+        $crate::impl_plugin_parameters! {@impl [] $raw_parameters, $parameter_type, echo_epsilon = $echo_epsilon}
+    };
+    (@table $raw_parameters: ident, $parameter_type: ident) => {
+        $crate::impl_plugin_parameters! {@impl [table] $raw_parameters, $parameter_type, echo_epsilon = 0.0}
+    };
+    (@table $raw_parameters: ident, $parameter_type: ident, echo_epsilon = $echo_epsilon: expr) => {
+        $crate::impl_plugin_parameters! {@impl [table] $raw_parameters, $parameter_type, echo_epsilon = $echo_epsilon}
+    };
+    (@impl [$($table: ident)?] $raw_parameters: ident, $parameter_type: ident, echo_epsilon = $echo_epsilon: expr) => {
+        impl $raw_parameters {
+            /// Values passed to `set_parameter` within this distance of the
+            /// current value are treated as echoes and ignored.
+            pub const ECHO_EPSILON: f32 = $echo_epsilon;
+
+            /// Calls `set_parameter` for each `(index, value)` pair. Returns how
+            /// many values were written, which excludes invalid indices and
+            /// echoes.
+            pub fn set_many(&self, pairs: &[(i32, f32)]) -> usize {
+                pairs
+                    .iter()
+                    .filter(|&&(index, value)| self.try_set_parameter(index, value))
+                    .count()
+            }
+
+            /// Like `set_parameter`, but returns whether the value was written.
+            pub fn try_set_parameter(&self, index: i32, value: f32) -> bool {
+                use std::convert::TryFrom;
+                if let Ok(parameter) = $parameter_type::try_from(index) {
+                    if $crate::__if_table!([$($table)?] {
+                        $raw_parameters::is_readonly(parameter)
+                    } else {
+                        false
+                    }) {
+                        return false;
+                    }
+
+                    // This is needed because some VST hosts, such as Ableton, echo a
+                    // parameter change back to the plugin. This causes issues such as
+                    // weird knob behavior where the knob "flickers" because the user tries
+                    // to change the knob value, but ableton keeps sending back old, echoed
+                    // values.
+                    if (self.get(parameter) - value).abs() <= $raw_parameters::ECHO_EPSILON {
+                        return false;
+                    }
+
+                    $crate::__set_from_host!(self, value, parameter);
+                    true
+                } else {
+                    $crate::__warn_bad_index!("set_parameter", index);
+                    false
+                }
+            }
+
+            /// Returns the parameter's text and units separately, as in
+            /// `get_parameter_text` and `get_parameter_label`, for GUIs which
+            /// lay them out apart. Unknown indices give two empty strings.
+            pub fn get_parameter_text_parts(&self, index: i32) -> (String, String) {
+                use std::convert::TryFrom;
+                if let Ok(parameter) = $parameter_type::try_from(index) {
+                    self.get_strings(parameter)
+                } else {
+                    ("".to_string(), "".to_string())
+                }
+            }
+        }
+
         impl vst::plugin::PluginParameters for $raw_parameters {
             fn get_parameter_label(&self, index: i32) -> String {
                 use std::convert::TryFrom;
@@ -34,7 +307,11 @@ macro_rules! impl_plugin_parameters {
             fn get_parameter_name(&self, index: i32) -> String {
                 use std::convert::TryFrom;
                 if let Ok(param) = $parameter_type::try_from(index) {
-                    param.to_string()
+                    $crate::__if_table!([$($table)?] {
+                        $raw_parameters::get_display_name(param).to_string()
+                    } else {
+                        param.to_string()
+                    })
                 } else {
                     "".to_string()
                 }
@@ -45,43 +322,62 @@ macro_rules! impl_plugin_parameters {
                 if let Ok(parameter) = $parameter_type::try_from(index) {
                     self.get(parameter)
                 } else {
+                    $crate::__warn_bad_index!("get_parameter", index);
                     0.0
                 }
             }
 
             fn set_parameter(&self, index: i32, value: f32) {
+                self.try_set_parameter(index, value);
+            }
+
+            fn can_be_automated(&self, index: i32) -> bool {
                 use std::convert::TryFrom;
                 if let Ok(parameter) = $parameter_type::try_from(index) {
-                    // This is needed because some VST hosts, such as Ableton, echo a
-                    // parameter change back to the plugin. This causes issues such as
-                    // weird knob behavior where the knob "flickers" because the user tries
-                    // to change the knob value, but ableton keeps sending back old, echoed
-                    // values.
-                    #[allow(clippy::float_cmp)]
-                    if self.get(parameter) == value {
-                        return;
-                    }
-
-                    self.set(value, parameter);
+                    $crate::__if_table!([$($table)?] {
+                        $raw_parameters::is_automatable(parameter)
+                    } else {
+                        true
+                    })
+                } else {
+                    $crate::__warn_bad_index!("can_be_automated", index);
+                    false
                 }
             }
 
-            fn can_be_automated(&self, index: i32) -> bool {
+            fn string_to_parameter(&self, index: i32, text: String) -> bool {
                 use std::convert::TryFrom;
-                $parameter_type::try_from(index).is_ok()
+                match $parameter_type::try_from(index) {
+                    Ok(parameter) => $crate::__if_table!([$($table)?] {
+                        !$raw_parameters::is_readonly(parameter)
+                            && self.set_from_string(parameter, &text)
+                    } else {
+                        false
+                    }),
+                    Err(_) => false,
+                }
             }
+        }
 
-            fn string_to_parameter(&self, _index: i32, _text: String) -> bool {
-                false
+        $crate::__if_table! {[$($table)?] {
+            impl $raw_parameters {
+                pub fn get_parameter_short_name(&self, index: i32) -> String {
+                    use std::convert::TryFrom;
+                    if let Ok(parameter) = $parameter_type::try_from(index) {
+                        $raw_parameters::get_short_name(parameter).to_string()
+                    } else {
+                        "".to_string()
+                    }
+                }
             }
-        }
+        } else {}}
     };
 }
 
 #[macro_export]
 macro_rules! impl_display {
      ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl std::fmt::Display for $parameter_type {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
@@ -92,16 +388,34 @@ macro_rules! impl_display {
     };
 }
 
+/// Implement `Debug` for `$parameter_type`, printing `$name($idx)` (ex:
+/// "Volume(0)"). Unlike `Display`, this tells apart rows which share a name.
+/// This is not part of `impl_all!`, since the enum may already derive `Debug`.
+/// Invoke it as `$table! {impl_debug}`.
+#[macro_export]
+macro_rules! impl_debug {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl std::fmt::Debug for $parameter_type {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($variant => write!(f, "{}({})", $name, $idx),)*
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_from_i32 {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl std::convert::TryFrom<i32> for $parameter_type {
-            type Error = ();
+            type Error = $crate::ParameterIndexError;
             fn try_from(x: i32) -> Result<Self, Self::Error> {
                 match x {
                     $($idx => Ok($variant),)*
-                    _ => Err(()),
+                    _ => Err($crate::ParameterIndexError(x)),
                 }
             }
         }
@@ -111,7 +425,7 @@ macro_rules! impl_from_i32 {
 #[macro_export]
 macro_rules! impl_into_i32 {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl std::convert::From<$parameter_type> for i32 {
             fn from(x: $parameter_type) -> i32 {
                 match x {
@@ -125,11 +439,109 @@ macro_rules! impl_into_i32 {
 #[macro_export]
 macro_rules! impl_get_ref {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Reads the given parameter's field as a normalized value,
+            /// converting from the row's `kind`. Each field is read through its
+            /// own `ParameterStore` type, so fields may have different types.
+            fn get_stored(&self, x: $parameter_type) -> f32 {
+                match x {
+                    $($variant => $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                        .to_normalized(
+                            $crate::ParameterStore::get(&self.$field_name),
+                            $crate::__table_column!(steps, 0; $($column = $value),*),
+                        ),)*
+                }
+            }
+
+            /// Writes a normalized value to the given parameter's field,
+            /// converting it to the row's `kind`.
+            fn set_stored(&self, x: $parameter_type, value: f32) {
+                match x {
+                    $($variant => $crate::ParameterStore::set(
+                        &self.$field_name,
+                        $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                            .from_normalized(value, $crate::__table_column!(steps, 0; $($column = $value),*)),
+                    ),)*
+                }
+            }
+        }
+
+        $crate::impl_get_kind! {
+            $raw_parameters, $parameter_type;
+            $($variant, $idx, $name, $field_name, $default, $string $(, $column = $value)*;)*
+        }
+    };
+}
+
+/// Like `impl_get_ref!`, but `get_stored` and `set_stored` index arrays of
+/// field accessors by the parameter's index instead of matching on it, which
+/// avoids a long chain of branches in large tables. This requires indices to
+/// run from zero with no gaps. `impl_all!` uses this when passed `jump_table`,
+/// and checks the indices then. `cargo bench` compares the two on a 200
+/// parameter table.
+#[macro_export]
+macro_rules! impl_get_ref_table {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            fn get_stored(&self, x: $parameter_type) -> f32 {
+                type Getter = fn(&$raw_parameters) -> f32;
+                const GETTERS: [Getter; $raw_parameters::PARAMETER_COUNT as usize] = {
+                    fn missing(_: &$raw_parameters) -> f32 {
+                        unreachable!()
+                    }
+                    let mut getters: [Getter; $raw_parameters::PARAMETER_COUNT as usize] =
+                        [missing; $raw_parameters::PARAMETER_COUNT as usize];
+                    $(getters[$idx as usize] = |raw| {
+                        $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                            .to_normalized(
+                                $crate::ParameterStore::get(&raw.$field_name),
+                                $crate::__table_column!(steps, 0; $($column = $value),*),
+                            )
+                    };)*
+                    getters
+                };
+                GETTERS[i32::from(x) as usize](self)
+            }
+
+            fn set_stored(&self, x: $parameter_type, value: f32) {
+                type Setter = fn(&$raw_parameters, f32);
+                const SETTERS: [Setter; $raw_parameters::PARAMETER_COUNT as usize] = {
+                    fn missing(_: &$raw_parameters, _: f32) {
+                        unreachable!()
+                    }
+                    let mut setters: [Setter; $raw_parameters::PARAMETER_COUNT as usize] =
+                        [missing; $raw_parameters::PARAMETER_COUNT as usize];
+                    $(setters[$idx as usize] = |raw, value| {
+                        $crate::ParameterStore::set(
+                            &raw.$field_name,
+                            $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                                .from_normalized(value, $crate::__table_column!(steps, 0; $($column = $value),*)),
+                        )
+                    };)*
+                    setters
+                };
+                SETTERS[i32::from(x) as usize](self, value)
+            }
+        }
+
+        $crate::impl_get_kind! {
+            $raw_parameters, $parameter_type;
+            $($variant, $idx, $name, $field_name, $default, $string $(, $column = $value)*;)*
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_get_kind {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl $raw_parameters {
-            fn get_ref(&self, x: $parameter_type) -> &vst::util::AtomicFloat {
+            /// Returns the row's `kind` column, or `Float` if the row omits it.
+            fn get_kind(x: $parameter_type) -> $crate::ParameterKind {
                 match x {
-                    $($variant => &self.$field_name,)*
+                    $($variant => $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*),)*
                 }
             }
         }
@@ -139,7 +551,7 @@ macro_rules! impl_get_ref {
 #[macro_export]
 macro_rules! impl_get_default {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl $raw_parameters {
             fn get_default(x: $parameter_type) -> f32 {
                 match x {
@@ -150,69 +562,1844 @@ macro_rules! impl_get_default {
     };
 }
 
+/// Implement `default_with_mock()` if the `mock` feature is enabled, and
+/// otherwise do nothing.
+#[cfg(feature = "mock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_default_with_mock {
+    ($raw_parameters: ident) => {
+        impl $raw_parameters {
+            /// Like `default`, but with a mock host which records the calls made
+            /// to it, for testing.
+            #[allow(dead_code)]
+            pub fn default_with_mock() -> (Self, $crate::mock::MockHostHandle) {
+                let (host, handle) = $crate::mock::host();
+                ($raw_parameters::default(host), handle)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "mock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_default_with_mock {
+    ($raw_parameters: ident) => {};
+}
+
 #[macro_export]
 macro_rules! impl_default {
+    (@extra $($extra_field:ident: $extra_value:expr),*;
+     $raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            $crate::__if_vst! {{
+                fn default(host: vst::plugin::HostCallback) -> Self {
+                    $raw_parameters {
+                        $($field_name: $crate::ParameterStore::new(
+                            $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                                .from_normalized($default, $crate::__table_column!(steps, 0; $($column = $value),*)),
+                        ),)*
+                        host,
+                        $($extra_field: $extra_value,)*
+                    }
+                }
+            } else {
+                fn default() -> Self {
+                    $raw_parameters {
+                        $($field_name: $crate::ParameterStore::new(
+                            $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*)
+                                .from_normalized($default, $crate::__table_column!(steps, 0; $($column = $value),*)),
+                        ),)*
+                        $($extra_field: $extra_value,)*
+                    }
+                }
+            }}
+        }
+
+        $crate::__impl_default_with_mock! {$raw_parameters}
+    };
+    ($($rows:tt)*) => {
+        $crate::impl_default! {@extra; $($rows)*}
+    };
+}
+
+#[macro_export]
+macro_rules! impl_is_automatable {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl $raw_parameters {
-            fn default(host: vst::plugin::HostCallback) -> Self {
-                $raw_parameters {
-                    $($field_name: vst::util::AtomicFloat::new($default),)*
-                    host,
+            /// Returns the row's `automatable` column, or true if the row omits it.
+            /// Readonly parameters are never automatable.
+            fn is_automatable(x: $parameter_type) -> bool {
+                let automatable = match x {
+                    $($variant => $crate::__table_column!(automatable, true; $($column = $value),*),)*
+                };
+                automatable && !$raw_parameters::is_readonly(x)
+            }
+
+            /// Returns the row's `readonly` column, or false if the row omits it.
+            /// Readonly parameters, such as meters, ignore writes from the host but
+            /// may still be set by the plugin with `set`.
+            pub fn is_readonly(x: $parameter_type) -> bool {
+                match x {
+                    $($variant => $crate::__table_column!(readonly, false; $($column = $value),*),)*
                 }
             }
         }
     };
 }
 
+/// Fails to compile if a row has a column which isn't one of those listed
+/// for `impl_all!`, such as a misspelt `automatible = false`, which would
+/// otherwise be ignored.
 #[macro_export]
-macro_rules! impl_get_set {
-    ($raw_parameters: ident, $parameter_type: ident) => {
+macro_rules! impl_assert_known_columns {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        $($($crate::__assert_known_column!($column, $name);)*)*
+    };
+}
+
+/// Expands to nothing for a known column, and to a `compile_error!` naming
+/// the column and row otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_known_column {
+    (automatable, $name:expr) => {};
+    (min, $name:expr) => {};
+    (max, $name:expr) => {};
+    (steps, $name:expr) => {};
+    (group, $name:expr) => {};
+    (short_name, $name:expr) => {};
+    (display_name, $name:expr) => {};
+    (parse, $name:expr) => {};
+    (aliases, $name:expr) => {};
+    (mod_range, $name:expr) => {};
+    (cc, $name:expr) => {};
+    (link, $name:expr) => {};
+    (readonly, $name:expr) => {};
+    (display_step, $name:expr) => {};
+    (curve, $name:expr) => {};
+    (bipolar, $name:expr) => {};
+    (unit, $name:expr) => {};
+    (kind, $name:expr) => {};
+    ($column:ident, $name:expr) => {
+        compile_error!(concat!(
+            "unknown column `",
+            stringify!($column),
+            "` for parameter ",
+            $name
+        ));
+    };
+}
+
+#[macro_export]
+macro_rules! impl_parameter_count {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl $raw_parameters {
-            pub fn set(&self, value: f32, parameter: $parameter_type) {
-                // These are needed so Ableton will notice parameter changes in the
-                // "Configure" window.
-                // TODO: investigate if I should send this only on mouseup/mousedown
-                self.host.begin_edit(parameter.into());
-                self.get_ref(parameter).set(value);
-                self.host.end_edit(parameter.into());
-            }
+            /// The number of rows in the parameter table.
+            pub const PARAMETER_COUNT: i32 = 0 $(+ $crate::__count_row!($field_name))*;
 
-            pub fn get(&self, parameter: $parameter_type) -> f32 {
-                self.get_ref(parameter).get()
+            /// One more than the largest index in the parameter table. This is
+            /// `PARAMETER_COUNT` when indices are contiguous, and is what state
+            /// kept per index should be sized by.
+            pub const INDEX_LIMIT: i32 = $crate::index_limit(&[$($idx),*]);
+
+            /// Returns `0..PARAMETER_COUNT`, for looping over every index the
+            /// host may ask for. This assumes indices are contiguous, which
+            /// `assert_contiguous_indices` can check.
+            pub const fn index_range() -> std::ops::Range<i32> {
+                0..$raw_parameters::PARAMETER_COUNT
             }
         }
     };
 }
 
 #[macro_export]
-macro_rules! impl_get_strings {
+macro_rules! impl_meta {
     ($raw_parameters: ident, $parameter_type: ident;
-     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr;)*) => {
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
         impl $raw_parameters {
-            /// Returns a user-facing text output for the given parameter. This is broken
-            /// into a tuple consisting of (`value`, `units`)
-            fn get_strings(&self, parameter: $parameter_type) -> (String, String) {
-                let params = Parameters::from(self);
-                match parameter {
-                    $($variant => $string(params.$field_name),)*
+            /// The `(index, name, default)` of every row in the parameter
+            /// table, in table order.
+            pub const META: &'static [(i32, &'static str, f32)] = &[$(($idx, $name, $default),)*];
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_descriptors {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns a descriptor for every row in the parameter table, in
+            /// table order.
+            pub fn descriptors() -> &'static [$crate::ParameterDescriptor] {
+                const DESCRIPTORS: &[$crate::ParameterDescriptor] = &[$($crate::ParameterDescriptor {
+                    index: $idx,
+                    name: $name,
+                    default: $default,
+                    min: $crate::__table_column!(min, 0.0; $($column = $value),*),
+                    max: $crate::__table_column!(max, 1.0; $($column = $value),*),
+                    unit: $crate::__table_column!(unit, ""; $($column = $value),*),
+                    steps: $crate::__table_column!(steps, 0; $($column = $value),*),
+                    kind: $crate::__table_column!(kind, $crate::ParameterKind::Float; $($column = $value),*),
+                },)*];
+                DESCRIPTORS
+            }
+        }
+    };
+}
+
+/// Fails to compile if two rows share the same `$idx`, pointing at the
+/// duplicated index. `TryFrom<i32>` would otherwise silently shadow the
+/// second parameter.
+#[macro_export]
+macro_rules! impl_assert_unique_indices {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        const _: () = {
+            #[deny(unreachable_patterns)]
+            fn assert_unique_indices(x: i32) {
+                match x {
+                    $($idx => (),)*
+                    _ => (),
+                }
+            }
+        };
+    };
+}
+
+/// Fails to compile if a row's `$field_name` isn't a field of
+/// `$raw_parameters`, with the error pointing at that row's field name.
+#[macro_export]
+macro_rules! impl_assert_fields_exist {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields_exist(raw: &$raw_parameters) {
+                $(let _ = &raw.$field_name;)*
+            }
+        };
+    };
+}
+
+/// Fails to compile unless the table's indices are exactly `0, 1, 2, ...`
+/// in some order. This is opt-in, since sparse layouts are otherwise allowed.
+#[macro_export]
+macro_rules! assert_contiguous_indices {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        const _: () = {
+            let indices: &[i32] = &[$($idx),*];
+            let mut expected = 0;
+            while expected < indices.len() {
+                let mut found = false;
+                let mut i = 0;
+                while i < indices.len() {
+                    if indices[i] == expected as i32 {
+                        found = true;
+                    }
+                    i += 1;
+                }
+                if !found {
+                    panic!("parameter indices must be contiguous from zero");
                 }
+                expected += 1;
+            }
+        };
+    };
+}
+
+/// Fails to compile if a row's `min` is above its `max`, or if its `$default`
+/// lies outside them. Since `$default` is normalized, this means checking it
+/// lies in `[0, 1]`. Only rows with a `min` or `max` column are checked.
+#[macro_export]
+macro_rules! impl_assert_defaults_in_range {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        $(const _: () = {
+            let has_range = $crate::__if_column!(min; $($column = $value),*; {
+                true
+            } else {
+                $crate::__if_column!(max; $($column = $value),*; { true } else { false })
+            });
+            if has_range {
+                let min: f32 = $crate::__table_column!(min, 0.0; $($column = $value),*);
+                let max: f32 = $crate::__table_column!(max, 1.0; $($column = $value),*);
+                let default: f32 = $default;
+                assert!(min <= max, concat!("min is above max for parameter ", $name));
+                assert!(0.0 <= default && default <= 1.0, concat!("default lies outside the range of parameter ", $name));
             }
+        };)*
+    };
+}
+
+/// Implement `MAX_PARAMS` for `$raw_parameters`, which is `max_params` if
+/// given and `vst_utils::MAX_PARAMS` otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_max_params {
+    ($raw_parameters: ident) => {
+        $crate::__impl_max_params! {$raw_parameters, max_params = $crate::MAX_PARAMS}
+    };
+    ($raw_parameters: ident, max_params = $max_params: expr) => {
+        impl $raw_parameters {
+            /// Every row's index must be below this.
+            pub const MAX_PARAMS: i32 = $max_params;
         }
     };
 }
 
+/// Fails to compile if a row's `$idx` isn't below `$raw_parameters::MAX_PARAMS`,
+/// naming the row in the error.
 #[macro_export]
-macro_rules! impl_all {
-    ($raw_parameters: ident, $parameter_type: ident, $table: ident) => {
-        impl_plugin_parameters! {$raw_parameters, $parameter_type}
-        impl_get_set! {$raw_parameters, $parameter_type}
-        $table! {impl_from_i32}
-        $table! {impl_into_i32}
-        $table! {impl_display}
-        $table! {impl_get_ref}
-        $table! {impl_default}
-        $table! {impl_get_default}
-        $table! {impl_get_strings}
+macro_rules! impl_assert_max_index {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        $(const _: () = {
+            let index: i32 = $idx;
+            assert!(index < $raw_parameters::MAX_PARAMS, concat!("index is not below MAX_PARAMS for parameter ", $name));
+        };)*
+    };
+}
+
+#[macro_export]
+macro_rules! impl_iter_all {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns every parameter in the table, in index order.
+            pub fn iter_all() -> impl Iterator<Item = $parameter_type> {
+                let mut parameters = [$($variant),*];
+                parameters.sort_unstable_by_key(|&parameter| i32::from(parameter));
+                IntoIterator::into_iter(parameters)
+            }
+        }
+
+        $crate::__impl_arbitrary! {$raw_parameters, $parameter_type}
+    };
+}
+
+/// Implement `Arbitrary` for `$parameter_type`, along with `randomize`, if the
+/// `arbitrary` feature is enabled, and otherwise do nothing.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_arbitrary {
+    ($raw_parameters: ident, $parameter_type: ident) => {
+        /// Picks any parameter in the table, for fuzzing.
+        impl<'a> $crate::arbitrary::Arbitrary<'a> for $parameter_type {
+            fn arbitrary(
+                u: &mut $crate::arbitrary::Unstructured<'a>,
+            ) -> $crate::arbitrary::Result<Self> {
+                let parameters: Vec<$parameter_type> = $raw_parameters::iter_all().collect();
+                u.choose(&parameters).copied()
+            }
+        }
+
+        impl $raw_parameters {
+            /// Sets every parameter to an arbitrary normalized value, without
+            /// notifying the host, for fuzzing presets and formatters.
+            pub fn randomize(
+                &self,
+                u: &mut $crate::arbitrary::Unstructured<'_>,
+            ) -> $crate::arbitrary::Result<()> {
+                for parameter in $raw_parameters::iter_all() {
+                    self.set_without_notify($crate::arbitrary_normalized(u)?, parameter);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_arbitrary {
+    ($raw_parameters: ident, $parameter_type: ident) => {};
+}
+
+/// Implement `egui_range` if the `egui` feature is enabled, and otherwise do
+/// nothing.
+#[cfg(feature = "egui")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_egui_range {
+    ($raw_parameters: ident, $parameter_type: ident) => {
+        impl $raw_parameters {
+            /// Returns the parameter's range for an egui slider, ex:
+            /// `ui.add(Slider::new(&mut value, raw.egui_range(parameter)))`.
+            pub fn egui_range(&self, parameter: $parameter_type) -> std::ops::RangeInclusive<f32> {
+                let (min, max) = $raw_parameters::get_range(parameter);
+                min..=max
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "egui"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_egui_range {
+    ($raw_parameters: ident, $parameter_type: ident) => {};
+}
+
+#[macro_export]
+macro_rules! impl_denormalize {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the `(min, max)` real-world range of the given parameter.
+            /// Rows which omit the `min`/`max` columns use `(0.0, 1.0)`.
+            pub fn get_range(x: $parameter_type) -> (f32, f32) {
+                match x {
+                    $($variant => (
+                        $crate::__table_column!(min, 0.0; $($column = $value),*),
+                        $crate::__table_column!(max, 1.0; $($column = $value),*),
+                    ),)*
+                }
+            }
+
+            /// Returns the step the parameter's displayed value is rounded to,
+            /// or 0.0 if it isn't rounded.
+            pub fn display_step(x: $parameter_type) -> f32 {
+                match x {
+                    $($variant => $crate::__table_column!(display_step, 0.0; $($column = $value),*),)*
+                }
+            }
+
+            /// Returns how the parameter maps onto its range. Rows which omit
+            /// the `curve` column use `Curve::Linear`.
+            pub fn get_curve(x: $parameter_type) -> $crate::Curve {
+                match x {
+                    $($variant => $crate::__table_column!(curve, $crate::Curve::Linear; $($column = $value),*),)*
+                }
+            }
+
+            /// Returns the parameter's value mapped from `[0, 1]` into its range.
+            pub fn get_denormalized(&self, parameter: $parameter_type) -> f32 {
+                let (min, max) = $raw_parameters::get_range(parameter);
+                $raw_parameters::get_curve(parameter).denormalize(min, max, self.get(parameter))
+            }
+
+            /// Returns the parameter's plain value for DSP code, mapped into its
+            /// range through its `curve`. This is the same as
+            /// `get_denormalized`, and the counterpart of the normalized `get`.
+            pub fn get_plain(&self, parameter: $parameter_type) -> f32 {
+                self.get_denormalized(parameter)
+            }
+
+            /// Sets the parameter from a value in its range.
+            pub fn set_denormalized(&self, value: f32, parameter: $parameter_type) {
+                let (min, max) = $raw_parameters::get_range(parameter);
+                self.set($raw_parameters::get_curve(parameter).normalize(min, max, value), parameter);
+            }
+        }
+
+        $crate::__impl_egui_range! {$raw_parameters, $parameter_type}
+    };
+}
+
+#[macro_export]
+macro_rules! impl_discrete {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the number of discrete steps the parameter has, or None
+            /// if it is continuous.
+            pub fn get_step_count(x: $parameter_type) -> Option<i32> {
+                let steps: i32 = match x {
+                    $($variant => $crate::__table_column!(steps, 0; $($column = $value),*),)*
+                };
+                if steps > 0 {
+                    Some(steps)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the parameter's value quantized into `0..steps`.
+            /// Continuous parameters always return 0.
+            pub fn get_discrete(&self, parameter: $parameter_type) -> i32 {
+                match $raw_parameters::get_step_count(parameter) {
+                    Some(steps) => (self.get(parameter) * (steps - 1) as f32)
+                        .round()
+                        .clamp(0.0, (steps - 1) as f32) as i32,
+                    None => 0,
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_modulation_range {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the furthest a modulation source may move the parameter
+            /// from its value, as a normalized distance, or 0.0 if the parameter
+            /// can't be modulated.
+            pub fn modulation_range(x: $parameter_type) -> f32 {
+                match x {
+                    $($variant => $crate::__table_column!(mod_range, 0.0; $($column = $value),*),)*
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_linked {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the row's `link` column, the parameter this one is
+            /// stereo-linked to, or None if the row omits it.
+            pub fn linked_partner(x: $parameter_type) -> Option<$parameter_type> {
+                match x {
+                    $($variant => $crate::__table_column!(link, None; $($column = $value),*),)*
+                }
+            }
+
+            /// Like `set`, but also sets the parameter's `linked_partner`, if it
+            /// has one, to the same value.
+            pub fn set_linked(&self, value: f32, parameter: $parameter_type) {
+                self.set(value, parameter);
+                if let Some(partner) = $raw_parameters::linked_partner(parameter) {
+                    self.set(value, partner);
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_get_parameter_group {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the name of the group the parameter is displayed in
+            /// (ex: "Filter"), or "" if it isn't in a group.
+            pub fn get_parameter_group(x: $parameter_type) -> &'static str {
+                match x {
+                    $($variant => $crate::__table_column!(group, ""; $($column = $value),*),)*
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_get_short_name {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the row's `short_name` column, or `$name` if the row omits it.
+            fn get_short_name(x: $parameter_type) -> &'static str {
+                match x {
+                    $($variant => $crate::__table_column!(short_name, $name; $($column = $value),*),)*
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_get_display_name {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the row's `display_name` column, or `$name` if the row omits it.
+            fn get_display_name(x: $parameter_type) -> &'static str {
+                match x {
+                    $($variant => $crate::__table_column!(display_name, $name; $($column = $value),*),)*
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_parameter_by_name {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the parameter whose `$name` matches `name`, ignoring
+            /// ASCII case, or `None` if no row matches. This binary searches a
+            /// table of name hashes built at compile time.
+            /// Rows' `aliases` are also matched, though only after every `$name`.
+            pub fn parameter_by_name(name: &str) -> Option<$parameter_type> {
+                use std::convert::TryFrom;
+                const SORTED: &[(u64, usize)] = &$crate::sorted_name_hashes([$($name),*]);
+                const ALIASES: &[(i32, &[&str])] = &[$(($idx, $crate::__table_column!(aliases, &[]; $($column = $value),*)),)*];
+                let index = $crate::find_name(SORTED, $raw_parameters::META, name).or_else(|| {
+                    ALIASES
+                        .iter()
+                        .find(|(_, aliases)| aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name)))
+                        .map(|&(index, _)| index)
+                })?;
+                $parameter_type::try_from(index).ok()
+            }
+
+            /// Returns the row's `aliases` column, or no aliases if the row omits it.
+            pub fn get_aliases(x: $parameter_type) -> &'static [&'static str] {
+                match x {
+                    $($variant => $crate::__table_column!(aliases, &[]; $($column = $value),*),)*
+                }
+            }
+        }
+
+        /// Looks the name up with `parameter_by_name`, so case is ignored and
+        /// aliases also match.
+        impl std::convert::TryFrom<&str> for $parameter_type {
+            type Error = $crate::ParameterNameError;
+            fn try_from(name: &str) -> Result<Self, Self::Error> {
+                $raw_parameters::parameter_by_name(name).ok_or_else(|| $crate::ParameterNameError(name.to_string()))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_text_to_value {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Parses user-entered text into a normalized value for the given
+            /// parameter, without setting it. Returns None if the text can't be
+            /// parsed, or if the row has no `parse` column.
+            pub fn text_to_value(&self, parameter: $parameter_type, text: &str) -> Option<f32> {
+                match parameter {
+                    $($variant => ($crate::__table_column!(parse, |_: &str| None; $($column = $value),*))(text),)*
+                }
+            }
+
+            /// Parses user-entered text with `text_to_value` and sets the
+            /// parameter to the result. Returns false if the text can't be parsed.
+            fn set_from_string(&self, parameter: $parameter_type, text: &str) -> bool {
+                match self.text_to_value(parameter, text) {
+                    Some(value) => {
+                        self.set(value, parameter);
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_midi_cc {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the MIDI CC which controls the parameter by default, if any.
+            pub fn cc_for_param(x: $parameter_type) -> Option<u8> {
+                match x {
+                    $($variant => $crate::__table_column!(cc, None; $($column = $value),*),)*
+                }
+            }
+
+            /// Returns the parameter controlled by the given MIDI CC, if any.
+            pub fn param_for_cc(cc: u8) -> Option<$parameter_type> {
+                $raw_parameters::iter_all()
+                    .find(|&parameter| $raw_parameters::cc_for_param(parameter) == Some(cc))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_field_accessors {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        $crate::paste::paste! {
+            impl $raw_parameters {
+                $(
+                    /// Returns the normalized value of this parameter.
+                    pub fn $field_name(&self) -> f32 {
+                        self.get($variant)
+                    }
+
+                    /// Sets the normalized value of this parameter.
+                    pub fn [<set_ $field_name>](&self, value: f32) {
+                        self.set(value, $variant);
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_state {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Migrates values saved with `saved_version` (see
+            /// `PresetMigration`), then sets each parameter from the value under
+            /// its `$name` or one of its `aliases`. Parameters without a value
+            /// are left alone, unless `reset_missing` is set.
+            #[doc(hidden)]
+            pub fn apply_saved_values(
+                &self,
+                saved_version: u32,
+                mut values: std::collections::HashMap<String, f32>,
+                reset_missing: bool,
+            ) {
+                use std::convert::TryFrom;
+                self.migrate_saved_values(saved_version, &mut values);
+                for &(index, name, default) in $raw_parameters::META {
+                    if let Ok(parameter) = $parameter_type::try_from(index) {
+                        let aliases = $raw_parameters::get_aliases(parameter);
+                        match $crate::migration::lookup(&values, name, aliases) {
+                            Some(value) => self.set(value, parameter),
+                            None if reset_missing => self.set(default, parameter),
+                            None => (),
+                        }
+                    }
+                }
+            }
+        }
+
+        impl $raw_parameters {
+            /// Saves every parameter into a chunk, for `get_preset_data`.
+            pub fn save_state(&self) -> Vec<u8> {
+                $crate::state::write(
+                    $raw_parameters::iter_all().map(|parameter| self.get(parameter)),
+                )
+            }
+
+            /// Loads a chunk written by `save_state`, for `load_preset_data`.
+            /// Chunks with an unknown version or a different number of
+            /// parameters are rejected without changing any parameter.
+            pub fn load_state(&self, data: &[u8]) -> Result<(), $crate::state::StateError> {
+                let values = $crate::state::read(data, $raw_parameters::PARAMETER_COUNT as usize)?;
+                for (parameter, value) in $raw_parameters::iter_all().zip(values) {
+                    self.set(value, parameter);
+                }
+                Ok(())
+            }
+
+            /// Saves every parameter into a chunk keyed by name, which
+            /// `load_named_state` can load even after the table changes.
+            pub fn save_named_state(&self) -> Vec<u8> {
+                use std::convert::TryFrom;
+                $crate::state::write_named(
+                    $raw_parameters::SAVED_VERSION,
+                    $raw_parameters::META
+                        .iter()
+                        .filter_map(|&(index, name, _)| {
+                            let parameter = $parameter_type::try_from(index).ok()?;
+                            Some((name, self.get(parameter)))
+                        }),
+                )
+            }
+
+            /// Loads a chunk written by `save_named_state`, migrating it first if
+            /// it was saved with an older version (see `PresetMigration`).
+            /// Each parameter is matched by its `$name` or one of its `aliases`,
+            /// and reset to its default if the chunk doesn't have it. Values for
+            /// names which are no longer in the table are ignored.
+            pub fn load_named_state(&self, data: &[u8]) -> Result<(), $crate::state::StateError> {
+                use std::convert::TryFrom;
+                let mut known_names = Vec::new();
+                for &(index, name, _) in $raw_parameters::META {
+                    known_names.push(name);
+                    if let Ok(parameter) = $parameter_type::try_from(index) {
+                        known_names.extend_from_slice($raw_parameters::get_aliases(parameter));
+                    }
+                }
+                let (saved_version, values) = $crate::state::read_named(data, &known_names)?;
+                self.apply_saved_values(saved_version, values, true);
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Expands to `$then` if `$option` is among the options passed to
+/// `impl_all!`, and to `$else` otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_option {
+    (track_dirty; [track_dirty $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (on_change; [on_change $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (track_touch; [track_touch $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (throttle_edits; [throttle_edits $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (track_sample_rate; [track_sample_rate $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (jump_table; [jump_table $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (migrate_presets; [migrate_presets $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    (track_generations; [track_generations $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($then)*
+    };
+    ($option:ident; [$other:ident $($rest:ident)*]; {$($then:tt)*} else {$($else:tt)*}) => {
+        $crate::__if_option! {$option; [$($rest)*]; {$($then)*} else {$($else)*}}
+    };
+    ($option:ident; []; {$($then:tt)*} else {$($else:tt)*}) => {
+        $($else)*
+    };
+}
+
+/// Implement `get`, `set` and the other accessors for `$raw_parameters`.
+/// `$parameter_type` must implement `Into<i32>`, and `$raw_parameters` must
+/// implement the following function
+/// get_ref(&self, $parameter_type) -> &impl ParameterStore
+///     returns the field storing the given parameter's normalized value
+/// and, with the `vst` feature, have a `host: vst::plugin::HostCallback` field.
+///
+/// `impl_all!` invokes this as `impl_get_set! {@table ...}`, which instead
+/// reads and writes the fields with the `get_stored` and `set_stored`
+/// functions generated by `impl_get_ref!`, so fields may have different types
+/// and `kind` columns are applied.
+#[macro_export]
+macro_rules! impl_get_set {
+    ($raw_parameters: ident, $parameter_type: ident) => {
+        $crate::impl_get_set! {@impl [] [] $raw_parameters, $parameter_type}
+    };
+    (@table $raw_parameters: ident, $parameter_type: ident $(, $option: ident)*) => {
+        $crate::impl_get_set! {@impl [table] [$($option)*] $raw_parameters, $parameter_type}
+    };
+    (@impl [$($table: ident)?] [$($option: ident)*] $raw_parameters: ident, $parameter_type: ident) => {
+        impl $raw_parameters {
+            fn notify_change(&self, parameter: $parameter_type) {
+                $crate::__if_option! {track_dirty; [$($option)*]; {
+                    self.dirty.mark(parameter.into());
+                } else {}}
+                $crate::__if_option! {track_generations; [$($option)*]; {
+                    self.generations.bump(parameter.into());
+                } else {}}
+                $crate::__if_option! {on_change; [$($option)*]; {
+                    self.on_change.call(self, parameter);
+                } else {
+                    let _ = parameter;
+                }}
+            }
+        }
+
+        $crate::__if_option! {on_change; [$($option)*]; {
+            impl $raw_parameters {
+                /// Registers a function to call after any parameter of this
+                /// instance is written, such as to recompute filter coefficients,
+                /// or removes it if `callback` is None. It runs on whichever
+                /// thread wrote the parameter, so it should be quick.
+                pub fn set_on_change(&self, callback: Option<fn(&$raw_parameters, $parameter_type)>) {
+                    self.on_change.set(callback);
+                }
+            }
+        } else {}}
+
+        $crate::__if_option! {track_dirty; [$($option)*]; {
+            const _: () = assert!(
+                $raw_parameters::INDEX_LIMIT <= 64,
+                "track_dirty only supports indices below 64"
+            );
+
+            impl $raw_parameters {
+                /// Returns a mask with bit `n` set for each parameter with index `n`
+                /// written since the last call, and clears it. Call this once per
+                /// block to recompute only what changed.
+                pub fn take_dirty(&self) -> u64 {
+                    self.dirty.take()
+                }
+            }
+        } else {}}
+
+        $crate::__if_vst! {{
+            $crate::__if_option! {track_touch; [$($option)*]; {
+                impl $raw_parameters {
+                    /// Starts an automation pass on the parameter. Until `untouch`,
+                    /// `set` sends `begin_edit` only on its first write, and no
+                    /// `end_edit`, so the host sees the pass as a single edit.
+                    pub fn touch(&self, parameter: $parameter_type) {
+                        self.touch.touch(parameter.into());
+                    }
+
+                    /// Ends an automation pass started by `touch`, sending `end_edit`
+                    /// if the parameter was written during it.
+                    pub fn untouch(&self, parameter: $parameter_type) {
+                        if self.touch.untouch(parameter.into()) {
+                            self.host.end_edit(parameter.into());
+                        }
+                    }
+                }
+            } else {}}
+        } else {}}
+
+        $crate::__if_option! {track_generations; [$($option)*]; {
+            impl $raw_parameters {
+                /// Returns a counter which goes up each time the parameter is
+                /// written, even to the value it already had.
+                pub fn generation(&self, parameter: $parameter_type) -> u32 {
+                    self.generations.get(parameter.into())
+                }
+
+                /// Returns whether the parameter was written since `generation`
+                /// returned `last_seen`.
+                pub fn parameter_changed_since(&self, parameter: $parameter_type, last_seen: u32) -> bool {
+                    self.generation(parameter) != last_seen
+                }
+            }
+        } else {}}
+
+        $crate::__if_option! {track_sample_rate; [$($option)*]; {
+            impl $raw_parameters {
+                /// Stores the host's sample rate. Call this from the plugin's
+                /// `set_sample_rate`.
+                pub fn set_sample_rate(&self, sample_rate: f32) {
+                    $crate::ParameterStore::set(&self.sample_rate, sample_rate);
+                }
+
+                /// Returns the sample rate last given to `set_sample_rate`, or
+                /// `DEFAULT_SAMPLE_RATE` if it was never called.
+                pub fn sample_rate(&self) -> f32 {
+                    $crate::ParameterStore::get(&self.sample_rate)
+                }
+            }
+        } else {}}
+
+        $crate::__if_option! {migrate_presets; [$($option)*]; {
+            impl $raw_parameters {
+                /// The preset version saved chunks and presets record.
+                #[doc(hidden)]
+                pub const SAVED_VERSION: u32 =
+                    <$raw_parameters as $crate::migration::PresetMigration>::PRESET_VERSION;
+
+                fn migrate_saved_values(
+                    &self,
+                    saved_version: u32,
+                    values: &mut std::collections::HashMap<String, f32>,
+                ) {
+                    if saved_version < $raw_parameters::SAVED_VERSION {
+                        $crate::migration::PresetMigration::migrate(self, saved_version, values);
+                    }
+                }
+            }
+        } else {
+            impl $raw_parameters {
+                #[doc(hidden)]
+                pub const SAVED_VERSION: u32 = 0;
+
+                fn migrate_saved_values(&self, _: u32, _: &mut std::collections::HashMap<String, f32>) {}
+            }
+        }}
+
+        impl $raw_parameters {
+            pub fn set(&self, value: f32, parameter: $parameter_type) {
+                $crate::__if_vst! {{
+                    $crate::__if_option! {track_touch; [$($option)*]; {
+                        // The edit began on the first write after `touch`, and ends
+                        // at `untouch`.
+                        if let Some(first_write) = self.touch.write(parameter.into()) {
+                            if first_write {
+                                self.host.begin_edit(parameter.into());
+                            }
+                            self.set_without_notify(value, parameter);
+                            return;
+                        }
+                    } else {}}
+                    $crate::__if_option! {throttle_edits; [$($option)*]; {
+                        // The value is still written, but the host only hears
+                        // about it once every `EDIT_THROTTLE_MS`.
+                        if !self.throttle.allow(parameter.into()) {
+                            self.set_without_notify(value, parameter);
+                            return;
+                        }
+                    } else {}}
+                    // These are needed so Ableton will notice parameter changes in the
+                    // "Configure" window.
+                    // TODO: investigate if I should send this only on mouseup/mousedown
+                    self.host.begin_edit(parameter.into());
+                    self.set_without_notify(value, parameter);
+                    self.host.end_edit(parameter.into());
+                } else {
+                    self.set_without_notify(value, parameter);
+                }}
+            }
+
+            /// Sets the parameter from the plugin's GUI and tells the host about the
+            /// new value, so it can record automation. UI code should use this
+            /// rather than `set_parameter`, since `set_parameter` ignores values
+            /// within `ECHO_EPSILON` of the current one to filter out host echoes.
+            pub fn set_from_gui(&self, value: f32, parameter: $parameter_type) {
+                $crate::__if_vst! {{
+                    self.host.begin_edit(parameter.into());
+                    self.set_without_notify(value, parameter);
+                    self.host.automate(parameter.into(), value);
+                    self.host.end_edit(parameter.into());
+                } else {
+                    self.set_without_notify(value, parameter);
+                }}
+            }
+
+            /// Like `set`, but clamps `value` to `[0, 1]` first. Hosts sometimes
+            /// send values just outside that range due to floating point error.
+            pub fn set_clamped(&self, value: f32, parameter: $parameter_type) {
+                self.set(value.clamp(0.0, 1.0), parameter);
+            }
+
+            /// Sets the parameter without notifying the host. Use this between
+            /// `begin_gesture` and `end_gesture` while the user drags a knob, so
+            /// the host sees one edit for the whole drag instead of one per value.
+            pub fn set_without_notify(&self, value: f32, parameter: $parameter_type) {
+                $crate::__if_table! {[$($table)?] {
+                    self.set_stored(parameter, value);
+                } else {
+                    $crate::ParameterStore::set(self.get_ref(parameter), value);
+                }}
+                self.notify_change(parameter);
+            }
+
+            $crate::__if_vst! {{
+                pub fn begin_gesture(&self, parameter: $parameter_type) {
+                    self.host.begin_edit(parameter.into());
+                }
+
+                pub fn end_gesture(&self, parameter: $parameter_type) {
+                    self.host.end_edit(parameter.into());
+                }
+
+                /// Asks the host to refresh its display of every parameter. Call this
+                /// once after setting many parameters at once, such as when loading a
+                /// preset, since some hosts won't otherwise move their knobs.
+                pub fn notify_all_changed(&self) {
+                    self.host.update_display();
+                }
+
+                /// Begins a gesture on the parameter which ends when the returned
+                /// guard is dropped. Prefer this over `begin_gesture`/`end_gesture`
+                /// so an interrupted drag can't leave the host mid-edit.
+                pub fn gesture(
+                    &self,
+                    parameter: $parameter_type,
+                ) -> $crate::ParamGesture<'_, $raw_parameters, $parameter_type> {
+                    $crate::ParamGesture::new(
+                        self.host,
+                        parameter.into(),
+                        self,
+                        parameter,
+                        $raw_parameters::set_without_notify,
+                    )
+                }
+            } else {}}
+
+            pub fn get(&self, parameter: $parameter_type) -> f32 {
+                $crate::__if_table!([$($table)?] {
+                    self.get_stored(parameter)
+                } else {
+                    $crate::ParameterStore::get(self.get_ref(parameter))
+                })
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_reset {
+    ($raw_parameters: ident, $parameter_type: ident) => {
+        impl $raw_parameters {
+            /// Sets the parameter back to its default. This goes through `set`,
+            /// so the host is notified of the change.
+            pub fn reset(&self, parameter: $parameter_type) {
+                self.set($raw_parameters::get_default(parameter), parameter);
+            }
+
+            $crate::__if_vst! {{
+                /// Sets the parameter back to its default inside a single gesture,
+                /// so the host records one edit, as for a double-clicked knob,
+                /// rather than something that looks like part of a drag.
+                pub fn reset_with_gesture(&self, parameter: $parameter_type) {
+                    self.gesture(parameter)
+                        .set($raw_parameters::get_default(parameter));
+                }
+            } else {}}
+
+            /// Sets every parameter back to its default.
+            pub fn reset_to_defaults(&self) {
+                for parameter in $raw_parameters::iter_all() {
+                    self.reset(parameter);
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_snapshot {
+    ($raw_parameters: ident, $parameter_type: ident) => {
+        impl $raw_parameters {
+            /// Reads every parameter into a `Parameters` struct, such as for a GUI
+            /// repaint. Each field is read separately, so this is not atomic across
+            /// fields: a parameter set from another thread partway through may or
+            /// may not be seen.
+            pub fn snapshot(&self) -> Parameters {
+                Parameters::from(self)
+            }
+
+            /// Reads every parameter's normalized value into an array, in index
+            /// order, without allocating. For tables with contiguous indices,
+            /// element `i` is the value of the parameter with index `i`. Like
+            /// `snapshot`, this is not atomic across parameters.
+            pub fn snapshot_array(&self) -> [f32; $raw_parameters::PARAMETER_COUNT as usize] {
+                let mut values = [0.0; $raw_parameters::PARAMETER_COUNT as usize];
+                for (value, parameter) in values.iter_mut().zip($raw_parameters::iter_all()) {
+                    *value = self.get(parameter);
+                }
+                values
+            }
+
+            /// Returns each parameter's index, `$name` and current normalized
+            /// value, in table order, such as for logging or broadcasting over
+            /// OSC. Values are read as the iterator advances.
+            pub fn iter_values(&self) -> impl Iterator<Item = (i32, &'static str, f32)> + '_ {
+                use std::convert::TryFrom;
+                $raw_parameters::META
+                    .iter()
+                    .filter_map(move |&(index, name, _)| {
+                        let parameter = $parameter_type::try_from(index).ok()?;
+                        Some((index, name, self.get(parameter)))
+                    })
+            }
+        }
+    };
+}
+
+/// `$string` is called with the parameter's field from `Parameters`. It may
+/// also take the whole `Parameters` struct as a second argument, for displays
+/// which depend on other parameters. For example, a rate which shows a note
+/// value when a sync toggle is on:
+/// ```ignore
+/// ParameterType::Rate, 3, "Rate", rate, 0.5, |rate: f32, params: &Parameters| {
+///     if params.sync > 0.5 {
+///         ("1/4".to_string(), "".to_string())
+///     } else {
+///         make_strings(rate, "Hz")
+///     }
+/// };
+/// ```
+#[macro_export]
+macro_rules! impl_get_strings {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            /// Returns the parameter's denormalized value along with its
+            /// user-facing text and units. If the row has a `min` or `max`
+            /// column, the value is clamped into that range before formatting,
+            /// and if it has a `display_step` column, it is rounded to that step.
+            pub fn get_parameter_value(&self, parameter: $parameter_type) -> $crate::ParameterValue {
+                let params = self.snapshot();
+                let (text, unit) = match parameter {
+                    $($variant => {
+                        // Rows without range columns are formatted as-is, since
+                        // their fields may be in any units.
+                        let value = $crate::__if_column!(min; $($column = $value),*; {
+                            let (min, max) = $raw_parameters::get_range(parameter);
+                            $crate::DisplayClamp::display_clamp(params.$field_name, min, max)
+                        } else {
+                            $crate::__if_column!(max; $($column = $value),*; {
+                                let (min, max) = $raw_parameters::get_range(parameter);
+                                $crate::DisplayClamp::display_clamp(params.$field_name, min, max)
+                            } else {
+                                params.$field_name
+                            })
+                        });
+                        let value = $crate::__if_column!(display_step; $($column = $value),*; {
+                            $crate::DisplayStep::display_step(value, $raw_parameters::display_step(parameter))
+                        } else {
+                            value
+                        });
+                        let (text, unit) = $crate::format_parameter($string, value, &params);
+                        if $crate::__table_column!(bipolar, false; $($column = $value),*) {
+                            ($crate::bipolar_text(text, self.get(parameter)), unit)
+                        } else {
+                            (text, unit)
+                        }
+                    })*
+                };
+                $crate::ParameterValue {
+                    value: self.get_denormalized(parameter),
+                    text,
+                    unit,
+                }
+            }
+
+            /// Returns a user-facing text output for the given parameter. This is broken
+            /// into a tuple consisting of (`value`, `units`)
+            fn get_strings(&self, parameter: $parameter_type) -> (String, String) {
+                let value = self.get_parameter_value(parameter);
+                (value.text, value.unit)
+            }
+        }
+    };
+}
+
+/// Implement `assert_parameters_roundtrip()` for `$raw_parameters`, which
+/// panics unless every parameter survives being converted into `Parameters`
+/// and back. This catches fields which were added to one struct but not the
+/// other. Requires both `Parameters: From<&$raw_parameters>` and
+/// `$raw_parameters: From<&Parameters>`, so it is not part of `impl_all!`.
+/// Invoke it as `$table! {impl_assert_parameters_roundtrip}` and call it from
+/// a test.
+#[macro_export]
+macro_rules! impl_assert_parameters_roundtrip {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            pub fn assert_parameters_roundtrip() {
+                let raw = $crate::__if_vst!({
+                    $raw_parameters::default(vst::plugin::HostCallback::default())
+                } else {
+                    $raw_parameters::default()
+                });
+                // Give every parameter a different value, so that fields which
+                // were swapped between the structs are caught too.
+                for (i, parameter) in $raw_parameters::iter_all().enumerate() {
+                    let value = (i + 1) as f32 / ($raw_parameters::PARAMETER_COUNT + 1) as f32;
+                    raw.set_without_notify(value, parameter);
+                }
+                let roundtrip = $raw_parameters::from(&Parameters::from(&raw));
+                $(
+                    assert!(
+                        (raw.get($variant) - roundtrip.get($variant)).abs() < 1e-5,
+                        "`{}` did not survive a round trip through `Parameters` (got {}, expected {})",
+                        stringify!($field_name),
+                        roundtrip.get($variant),
+                        raw.get($variant),
+                    );
+                )*
+            }
+        }
+    };
+}
+
+/// Implement `apply(&self, &Parameters)` for `$raw_parameters`, which sets
+/// every parameter from the matching field of a `Parameters` struct. Fields
+/// are read as real-world `f32` values and normalized through the row's
+/// `min`/`max` range, or as a step for rows with a `steps` column, so this is
+/// the reverse of a `From<&$raw_parameters>` which reads fields with
+/// `get_denormalized` and `get_discrete`. Also implements
+/// `from_parameters(Parameters, HostCallback)`, which builds a
+/// `$raw_parameters` from the same fields without notifying the host. This is
+/// not part of `impl_all!`. Invoke it as `$table! {impl_set_from_parameters}`.
+#[macro_export]
+macro_rules! impl_set_from_parameters {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            pub fn apply(&self, parameters: &Parameters) {
+                $(self.set($raw_parameters::normalize_field(parameters.$field_name, $variant), $variant);)*
+            }
+
+            $crate::__if_vst! {{
+                pub fn from_parameters(parameters: Parameters, host: vst::plugin::HostCallback) -> Self {
+                    let raw_parameters = $raw_parameters::default(host);
+                    $(raw_parameters.set_without_notify(
+                        $raw_parameters::normalize_field(parameters.$field_name, $variant),
+                        $variant,
+                    );)*
+                    raw_parameters
+                }
+            } else {
+                pub fn from_parameters(parameters: Parameters) -> Self {
+                    let raw_parameters = $raw_parameters::default();
+                    $(raw_parameters.set_without_notify(
+                        $raw_parameters::normalize_field(parameters.$field_name, $variant),
+                        $variant,
+                    );)*
+                    raw_parameters
+                }
+            }}
+
+            fn normalize_field(value: f32, parameter: $parameter_type) -> f32 {
+                match $raw_parameters::get_step_count(parameter) {
+                    Some(steps) if steps > 1 => value / (steps - 1) as f32,
+                    _ => {
+                        let (min, max) = $raw_parameters::get_range(parameter);
+                        $raw_parameters::get_curve(parameter).normalize(min, max, value)
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Implement `Default` for `Parameters` from the table's `$default` column,
+/// mapped into each row's range through its `curve`, or onto a step for rows
+/// with a `steps` column, as `apply` reads them. Both structs then share one
+/// set of defaults. `Parameters` must have exactly one `f32` field per
+/// row, named after `$field_name`. This is not part of `impl_all!`. Invoke it
+/// as `$table! {impl_parameters_default}`.
+#[macro_export]
+macro_rules! impl_parameters_default {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl Default for Parameters {
+            fn default() -> Self {
+                Parameters {
+                    $($field_name: match $raw_parameters::get_step_count($variant) {
+                        Some(steps) if steps > 1 => ($default * (steps - 1) as f32).round(),
+                        _ => {
+                            let (min, max) = $raw_parameters::get_range($variant);
+                            $raw_parameters::get_curve($variant).denormalize(min, max, $default)
+                        }
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+/// Define a `DenormalizedParameters` struct with an `f32` field per row,
+/// named after `$field_name`, holding the parameter's real-world value (ex:
+/// Hz or dB) mapped through its range and `curve`, so DSP code can read
+/// meaningful values directly. Also implements `denormalized(&self)` for
+/// `$raw_parameters`, which fills one in, and `set_from_denormalized`, which
+/// sets every parameter from one. This is not part of `impl_all!`, since it
+/// defines a type. Invoke it as `$table! {impl_denormalized_parameters}`.
+#[macro_export]
+macro_rules! impl_denormalized_parameters {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct DenormalizedParameters {
+            $(pub $field_name: f32,)*
+        }
+
+        impl $raw_parameters {
+            pub fn denormalized(&self) -> DenormalizedParameters {
+                DenormalizedParameters {
+                    $($field_name: self.get_denormalized($variant),)*
+                }
+            }
+
+            pub fn set_from_denormalized(&self, parameters: &DenormalizedParameters) {
+                $(self.set_denormalized(parameters.$field_name, $variant);)*
+            }
+        }
+    };
+}
+
+/// Implement `params_equal(&self, &Parameters, epsilon)` for
+/// `$raw_parameters`, which returns whether every field of the current
+/// `snapshot` is within `epsilon` of the matching field of another
+/// `Parameters`, such as the last saved preset. Fields must be `f32`, so this
+/// is not part of `impl_all!`. Invoke it as `$table! {impl_params_equal}`.
+#[macro_export]
+macro_rules! impl_params_equal {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            pub fn params_equal(&self, other: &Parameters, epsilon: f32) -> bool {
+                let current = self.snapshot();
+                true $(&& (current.$field_name - other.$field_name).abs() <= epsilon)*
+            }
+        }
+    };
+}
+
+/// Implement `to_preset` and `from_preset` for `$raw_parameters`, which save
+/// and load every parameter's normalized value keyed by `$name`. Because
+/// presets are keyed by name, they still load after parameters are reordered.
+/// When loading, names which aren't in the table are ignored and parameters
+/// missing from the preset keep their current value. Values which aren't
+/// finite are saved as the parameter's default.
+/// Also implements `to_json` and `from_json`, which do the same with a pretty
+/// printed JSON string (ex: `{ "Master Volume": 0.5 }`) for debugging.
+///
+/// For changes which `aliases` can't express, such as splitting one parameter
+/// into two, implement `vst_utils::migration::PresetMigration` for
+/// `$raw_parameters` and pass `migrate_presets` to `impl_all!`. Presets and
+/// chunks from `save_named_state` then record `PRESET_VERSION`, and loading
+/// one saved with an older version (or none, which counts as 0) first passes
+/// its version and values to `migrate`:
+///
+/// ```ignore
+/// impl PresetMigration for RawParameters {
+///     const PRESET_VERSION: u32 = 1;
+///
+///     fn migrate(&self, version: u32, values: &mut HashMap<String, f32>) {
+///         if version < 1 {
+///             if let Some(value) = values.remove("Cutoff") {
+///                 values.insert("Low Cutoff".to_string(), value);
+///                 values.insert("High Cutoff".to_string(), value);
+///             }
+///         }
+///     }
+/// }
+///
+/// impl_all!(RawParameters, ParameterType, table, migrate_presets);
+/// ```
+///
+/// Chunks from `save_state` are stored by index rather than name, so they
+/// aren't migrated.
+/// This is not part of `impl_all!`. Invoke it as `$table! {impl_preset_serde}`.
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! impl_preset_serde {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:expr, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        impl $raw_parameters {
+            fn preset_values(&self) -> Vec<(&'static str, f32, f32)> {
+                let mut values = vec![$(($name, self.get($variant), $default),)*];
+                let version = $raw_parameters::SAVED_VERSION as f32;
+                if version > 0.0 {
+                    values.push(($crate::preset::VERSION_KEY, version, version));
+                }
+                values
+            }
+
+            fn apply_preset_values(&self, mut values: std::collections::HashMap<String, f32>) {
+                let saved_version = $crate::preset::take_version(&mut values);
+                self.apply_saved_values(saved_version, values, false);
+            }
+
+            pub fn to_preset(&self) -> Vec<u8> {
+                $crate::preset::serialize(&self.preset_values())
+            }
+
+            pub fn from_preset(&self, data: &[u8]) -> Result<(), $crate::preset::PresetError> {
+                self.apply_preset_values($crate::preset::deserialize(data)?);
+                Ok(())
+            }
+
+            pub fn to_json(&self) -> String {
+                $crate::preset::to_json(&self.preset_values())
+            }
+
+            pub fn from_json(&self, json: &str) -> Result<(), $crate::preset::PresetError> {
+                self.apply_preset_values($crate::preset::from_json(json)?);
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implement `clap_adapter::ClapParams` for `$raw_parameters`, using the same
+/// functions `impl_all!` generates for the VST plugin. This is not part of
+/// `impl_all!`. Invoke it as `$table! {impl_clap_params}` after `impl_all!`.
+/// Requires the `clap` feature.
+///
+/// CLAP hosts ask for parameter info by position rather than by id, so this
+/// also checks that the table's indices are contiguous, as
+/// `assert_contiguous_indices` does. Each parameter is then at the position
+/// of its index.
+///
+/// CLAP hosts may also ask for the text of any value, not just the current
+/// one. The text is formatted by calling the row's `$string` with the CLAP
+/// value, which is what `Parameters` holds for fields which are read with
+/// `get_denormalized` or `get_discrete`. Formatters which take `Parameters`
+/// are given the current values of the other parameters.
+#[cfg(feature = "clap")]
+#[macro_export]
+macro_rules! impl_clap_params {
+    ($raw_parameters: ident, $parameter_type: ident;
+     $($variant:pat, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        $crate::assert_contiguous_indices! {$raw_parameters, $parameter_type;
+            $($variant, $idx, $name, $field_name, $default, $string $(, $column = $value)*;)*}
+
+        impl $raw_parameters {
+            /// Converts a normalized value into CLAP's plain value for the
+            /// parameter.
+            fn to_clap_value(parameter: $parameter_type, value: f32) -> f64 {
+                let (min, max) = $raw_parameters::get_range(parameter);
+                match $raw_parameters::get_step_count(parameter) {
+                    Some(steps) => min as f64 + (value * (steps - 1) as f32).round() as f64,
+                    None => $raw_parameters::get_curve(parameter).denormalize(min, max, value) as f64,
+                }
+            }
+
+            /// Converts CLAP's plain value for the parameter into a normalized
+            /// value.
+            fn from_clap_value(parameter: $parameter_type, value: f64) -> f32 {
+                let (min, max) = $raw_parameters::get_range(parameter);
+                match $raw_parameters::get_step_count(parameter) {
+                    Some(steps) if steps > 1 => {
+                        ((value - min as f64).round() as f32 / (steps - 1) as f32).clamp(0.0, 1.0)
+                    }
+                    Some(_) => 0.0,
+                    None => $raw_parameters::get_curve(parameter)
+                        .normalize(min, max, value as f32)
+                        .clamp(0.0, 1.0),
+                }
+            }
+        }
+
+        impl $crate::clap_adapter::ClapParams for $raw_parameters {
+            fn param_count(&self) -> u32 {
+                $raw_parameters::PARAMETER_COUNT as u32
+            }
+
+            fn param_info(&self, index: u32) -> Option<$crate::clap_adapter::ClapParamInfo> {
+                use std::convert::TryFrom;
+                let parameter = $parameter_type::try_from(index as i32).ok()?;
+                let steps = $raw_parameters::get_step_count(parameter);
+                Some($crate::clap_adapter::ClapParamInfo {
+                    id: index,
+                    name: $raw_parameters::get_display_name(parameter).to_string(),
+                    module: $raw_parameters::get_parameter_group(parameter).to_string(),
+                    min_value: $raw_parameters::to_clap_value(parameter, 0.0),
+                    max_value: $raw_parameters::to_clap_value(parameter, 1.0),
+                    default_value: $raw_parameters::to_clap_value(
+                        parameter,
+                        $raw_parameters::get_default(parameter),
+                    ),
+                    is_automatable: $raw_parameters::is_automatable(parameter),
+                    is_stepped: steps.is_some(),
+                    is_readonly: $raw_parameters::is_readonly(parameter),
+                })
+            }
+
+            fn param_value(&self, id: u32) -> Option<f64> {
+                use std::convert::TryFrom;
+                let parameter = $parameter_type::try_from(id as i32).ok()?;
+                Some($raw_parameters::to_clap_value(parameter, self.get(parameter)))
+            }
+
+            fn set_param_value(&self, id: u32, value: f64) -> bool {
+                use std::convert::TryFrom;
+                match $parameter_type::try_from(id as i32) {
+                    Ok(parameter) if !$raw_parameters::is_readonly(parameter) => {
+                        self.set_without_notify(
+                            $raw_parameters::from_clap_value(parameter, value),
+                            parameter,
+                        );
+                        true
+                    }
+                    _ => false,
+                }
+            }
+
+            fn param_value_to_text(&self, id: u32, value: f64) -> Option<String> {
+                use std::convert::TryFrom;
+                let parameter = $parameter_type::try_from(id as i32).ok()?;
+                let params = Parameters::from(self);
+                let (value, units) = match parameter {
+                    $($variant => $crate::format_parameter($string, value as f32, &params),)*
+                };
+                if units.is_empty() {
+                    Some(value)
+                } else {
+                    Some(format!("{} {}", value, units))
+                }
+            }
+
+            fn param_text_to_value(&self, id: u32, text: &str) -> Option<f64> {
+                use std::convert::TryFrom;
+                let parameter = $parameter_type::try_from(id as i32).ok()?;
+                let value = self.text_to_value(parameter, text)?;
+                Some($raw_parameters::to_clap_value(parameter, value))
+            }
+        }
+    };
+}
+
+/// Implement everything for `$raw_parameters` using the rows of `$table`.
+/// `$table` must be a macro which invokes the macro it is given with
+/// `$raw_parameters, $parameter_type;` followed by one row per parameter:
+/// `$variant, $idx, $name, $field_name, $default, $string;`
+/// Rows may end with optional `name = value` columns, which take a default
+/// when omitted:
+/// automatable = bool (default true)
+///     whether the host may automate the parameter
+/// readonly = bool (default false)
+///     whether the host is prevented from setting the parameter, as for a
+///     meter. Readonly parameters are also not automatable
+/// min = f32, max = f32 (default 0.0 and 1.0)
+///     the real-world range used by `get_denormalized`/`set_denormalized`
+/// curve = Curve (default Curve::Linear)
+///     how `get_denormalized`/`set_denormalized` map onto `min`/`max`, ex:
+///     `Curve::Log` for a cutoff from 20 to 20000 Hz
+/// bipolar = bool (default false)
+///     whether the parameter is centered on zero, like pan. The text of
+///     bipolar parameters reads "0" at the center and has a "+" above it
+/// steps = i32 (default 0)
+///     the number of discrete values the parameter snaps to, or 0 if continuous
+/// display_step = f32 (default 0.0)
+///     the step the displayed value is rounded to (ex: 1.0 to show a cutoff to
+///     the nearest Hz), or 0.0 for no rounding
+/// unit = &'static str (default "")
+///     the unit shown next to the parameter's widget in `descriptors`
+/// group = &'static str (default "")
+///     the group the parameter is displayed under (ex: "Filter")
+/// short_name = &'static str (default `$name`)
+///     an abbreviated name (ex: "Cut") for hosts with narrow parameter lists
+/// display_name = &'static str (default `$name`)
+///     the name shown in the host's parameter list, leaving `Display` free to
+///     show something else (ex: for logging)
+/// aliases = &'static [&'static str] (default &[])
+///     names the parameter used to have, which `parameter_by_name` and
+///     preset loading also accept
+/// parse = fn(&str) -> Option<f32> (default parses nothing)
+///     turns text typed into the host (ex: "6 dB") into a normalized value
+/// cc = Option<u8> (default None)
+///     the MIDI CC which controls the parameter by default
+/// link = Option<$parameter_type> (default None)
+///     the other half of a stereo pair (ex: the right gain of a left gain),
+///     which `set_linked` also sets. Give both rows a `link` to each other
+/// mod_range = f32 (default 0.0)
+///     the furthest, as a normalized distance, that an LFO or envelope may
+///     move the parameter. 0.0 means it can't be modulated
+/// kind = ParameterKind (default ParameterKind::Float)
+///     what the field stores. `Bool` fields may be an `AtomicBool`, and `Int`
+///     fields an `AtomicI32` holding a step from `0` to `steps - 1`
+/// Hosts expect indices to run from zero with no gaps. To enforce this, also
+/// invoke `$table! {assert_contiguous_indices}` next to `impl_all!`.
+/// These options may follow `$table`, in any order:
+/// echo_epsilon = f32
+///     forwarded to `impl_plugin_parameters!`
+/// max_params = i32 (default `vst_utils::MAX_PARAMS`)
+///     every index must be below this, or the table fails to compile
+/// track_dirty
+///     generates `take_dirty`, for polling which parameters changed each
+///     block. This needs a `dirty: DirtyFlags` field on `$raw_parameters`.
+///     A `u64` has one bit per index, so every index must be below 64, which
+///     is checked at compile time. Larger tables can use `on_change` instead
+/// on_change
+///     generates `set_on_change`, for registering a function to call after
+///     each write. This needs an `on_change: ChangeCallback` field
+/// track_touch
+///     generates `touch` and `untouch`, for hosts which expect one edit per
+///     automation pass. This needs a `touch: TouchFlags` field, and the `vst`
+///     feature
+/// throttle_edits
+///     limits the edits `set` sends the host to one per parameter every
+///     `EDIT_THROTTLE_MS`. This needs a `throttle: EditThrottle` field
+/// track_sample_rate
+///     generates `set_sample_rate` and `sample_rate`, to keep the host's
+///     sample rate next to the parameters for `SmoothedParam::sync_sample_rate`.
+///     This needs a `sample_rate` field of any `ParameterStore` type, such as
+///     `AtomicFloat`, starting at `DEFAULT_SAMPLE_RATE`
+/// jump_table
+///     uses `impl_get_ref_table!` rather than `impl_get_ref!`, which may be
+///     faster for large tables. This requires contiguous indices, so it also
+///     checks them as `assert_contiguous_indices` does
+/// migrate_presets
+///     updates presets and named chunks saved with an older version as they
+///     load. This needs `$raw_parameters` to implement `PresetMigration`
+/// track_generations
+///     generates `generation` and `parameter_changed_since`, for GUIs which
+///     repaint only what changed. This needs a `generations: Generations` field
+///
+/// Every `$field_name` field must have a `ParameterStore` type which suits
+/// its `kind` (ex: `vst::util::AtomicFloat` for `Float`). With the `vst`
+/// feature (the default), `$raw_parameters` must also have a
+/// `host: vst::plugin::HostCallback` field, which `set` notifies. Without it, `PluginParameters` isn't implemented,
+/// `default()` takes no host, and there are no gesture methods.
+#[macro_export]
+macro_rules! impl_all {
+    ($raw_parameters: ident, $parameter_type: ident, $table: ident $($options: tt)*) => {
+        $crate::__impl_all_options! {[$raw_parameters, $parameter_type, $table] [] [] [] [] $($options)*}
+    };
+}
+
+/// Like `impl_all!`, and takes the same arguments, but also implements
+/// `num_parameters()` for filling in `vst::plugin::Info`, as in
+/// `Info { parameters: RawParameters::num_parameters(), ..Default::default() }`,
+/// so the count can't fall out of sync with the table. This assumes indices
+/// are contiguous, which `assert_contiguous_indices` can check.
+#[macro_export]
+macro_rules! impl_all_with_info {
+    ($raw_parameters: ident, $parameter_type: ident, $($rest:tt)*) => {
+        $crate::impl_all!($raw_parameters, $parameter_type, $($rest)*);
+
+        impl $raw_parameters {
+            /// The number of parameters to give the host in `Info::parameters`.
+            pub const fn num_parameters() -> i32 {
+                $raw_parameters::PARAMETER_COUNT
+            }
+        }
+    };
+}
+
+/// Parses the options passed to `impl_all!`, collecting them into
+/// `[echo_epsilon] [max_params] [options] [extra fields]`, then forwards to
+/// `__impl_all!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_all_options {
+    ([$raw_parameters: ident, $parameter_type: ident, $table: ident] [$($echo_epsilon: expr)?] [$($max_params: expr)?]
+     [$($option: ident)*] [$($extras: tt)*]) => {
+        $crate::__impl_all! {($) $raw_parameters, $parameter_type, $table, [$($echo_epsilon)?], [$($max_params)?],
+            [$($option)*], [$($extras)*]}
+    };
+    ($header: tt [] $max_params: tt $options: tt $extras: tt, echo_epsilon = $echo_epsilon: expr $(, $($rest: tt)*)?) => {
+        $crate::__impl_all_options! {$header [$echo_epsilon] $max_params $options $extras $(, $($rest)*)?}
+    };
+    ($header: tt $echo_epsilon: tt [] $options: tt $extras: tt, max_params = $max_params: expr $(, $($rest: tt)*)?) => {
+        $crate::__impl_all_options! {$header $echo_epsilon [$max_params] $options $extras $(, $($rest)*)?}
+    };
+    ($header: tt $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*], track_dirty $($rest: tt)*) => {
+        $crate::__impl_all_options! {$header $echo_epsilon $max_params [$($option)* track_dirty]
+            [$($extras)* dirty: $crate::DirtyFlags::new(),] $($rest)*}
+    };
+    ($header: tt $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*], on_change $($rest: tt)*) => {
+        $crate::__impl_all_options! {$header $echo_epsilon $max_params [$($option)* on_change]
+            [$($extras)* on_change: $crate::ChangeCallback::new(),] $($rest)*}
+    };
+    ([$raw_parameters: ident, $($header: tt)*] $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*],
+     track_touch $($rest: tt)*) => {
+        $crate::__impl_all_options! {[$raw_parameters, $($header)*] $echo_epsilon $max_params [$($option)* track_touch]
+            [$($extras)* touch: $crate::TouchFlags::new($raw_parameters::INDEX_LIMIT as usize),] $($rest)*}
+    };
+    ([$raw_parameters: ident, $($header: tt)*] $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*],
+     throttle_edits $($rest: tt)*) => {
+        $crate::__impl_all_options! {[$raw_parameters, $($header)*] $echo_epsilon $max_params [$($option)* throttle_edits]
+            [$($extras)* throttle: $crate::EditThrottle::new($raw_parameters::INDEX_LIMIT as usize),] $($rest)*}
+    };
+    ($header: tt $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*], track_sample_rate $($rest: tt)*) => {
+        $crate::__impl_all_options! {$header $echo_epsilon $max_params [$($option)* track_sample_rate]
+            [$($extras)* sample_rate: $crate::ParameterStore::new($crate::DEFAULT_SAMPLE_RATE),] $($rest)*}
+    };
+    ($header: tt $echo_epsilon: tt $max_params: tt [$($option: ident)*] $extras: tt, jump_table $($rest: tt)*) => {
+        $crate::__impl_all_options! {$header $echo_epsilon $max_params [$($option)* jump_table] $extras $($rest)*}
+    };
+    ($header: tt $echo_epsilon: tt $max_params: tt [$($option: ident)*] $extras: tt, migrate_presets $($rest: tt)*) => {
+        $crate::__impl_all_options! {$header $echo_epsilon $max_params [$($option)* migrate_presets] $extras $($rest)*}
+    };
+    ([$raw_parameters: ident, $($header: tt)*] $echo_epsilon: tt $max_params: tt [$($option: ident)*] [$($extras: tt)*],
+     track_generations $($rest: tt)*) => {
+        $crate::__impl_all_options! {[$raw_parameters, $($header)*] $echo_epsilon $max_params [$($option)* track_generations]
+            [$($extras)* generations: $crate::Generations::new($raw_parameters::INDEX_LIMIT as usize),] $($rest)*}
+    };
+}
+
+/// Implementation of `impl_all!`. `$extra_field`s are the fields the options
+/// need, which `impl_default!` initializes alongside the table's fields.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_all {
+    (($d:tt) $raw_parameters: ident, $parameter_type: ident, $table: ident, [$($echo_epsilon: expr)?],
+     [$($max_params: expr)?], [$($option: ident)*], [$($extra_field: ident: $extra_value: expr,)*]) => {
+        $crate::__if_vst! {{
+            $crate::impl_plugin_parameters! {@table $raw_parameters, $parameter_type $(, echo_epsilon = $echo_epsilon)?}
+        } else {}}
+        $crate::impl_get_set! {@table $raw_parameters, $parameter_type $(, $option)*}
+        $crate::impl_reset! {$raw_parameters, $parameter_type}
+        $table! {impl_assert_fields_exist}
+        $crate::__impl_max_params! {$raw_parameters $(, max_params = $max_params)?}
+        $table! {impl_assert_max_index}
+        $crate::impl_snapshot! {$raw_parameters, $parameter_type}
+        $table! {impl_from_i32}
+        $table! {impl_into_i32}
+        $table! {impl_display}
+        $crate::__if_option! {jump_table; [$($option)*]; {
+            $table! {assert_contiguous_indices}
+            $table! {impl_get_ref_table}
+        } else {
+            $table! {impl_get_ref}
+        }}
+        $crate::paste::paste! {
+            macro_rules! [<__ $raw_parameters _impl_default>] {
+                ($d($d rows:tt)*) => {
+                    $crate::impl_default! {@extra $($extra_field: $extra_value),*; $d($d rows)*}
+                };
+            }
+            $table! {[<__ $raw_parameters _impl_default>]}
+        }
+        $table! {impl_get_default}
+        $table! {impl_get_strings}
+        $table! {impl_is_automatable}
+        $table! {impl_assert_known_columns}
+        $table! {impl_parameter_count}
+        $table! {impl_meta}
+        $table! {impl_descriptors}
+        $table! {impl_assert_unique_indices}
+        $table! {impl_assert_defaults_in_range}
+        $table! {impl_iter_all}
+        $table! {impl_denormalize}
+        $table! {impl_discrete}
+        $table! {impl_get_parameter_group}
+        $table! {impl_modulation_range}
+        $table! {impl_get_short_name}
+        $table! {impl_get_display_name}
+        $table! {impl_parameter_by_name}
+        $table! {impl_text_to_value}
+        $table! {impl_midi_cc}
+        $table! {impl_linked}
+        $table! {impl_field_accessors}
+        $table! {impl_state}
+    };
+}
+
+/// Defines the `$parameter_type` enum from a table and then calls
+/// `impl_all!`, so the list of variants only has to be written once:
+/// ```ignore
+/// define_parameters! {
+///     RawParameters, pub ParameterType, parameter_table;
+///     Volume, 0, "Volume", volume, 0.5, make_strings_db;
+/// }
+/// ```
+/// Rows are the same as in a table for `impl_all!`, except that `$variant`
+/// is the bare name of the variant (ex: `Volume` instead of
+/// `ParameterType::Volume`). The enum has the given visibility, derives
+/// `Copy`, `Clone`, `PartialEq`, and `Eq`, and implements `Debug` with
+/// `impl_debug!`. The table is defined as a macro
+/// named `$table`, so it can be given to the other table macros (ex:
+/// `parameter_table! {assert_contiguous_indices}`), and is followed by any
+/// options `impl_all!` takes. The vst_utils macros don't need to be imported.
+#[macro_export]
+macro_rules! define_parameters {
+    ($($tokens:tt)*) => {
+        $crate::__define_parameters! {($) $($tokens)*}
+    };
+}
+
+/// Implementation of `define_parameters!`. `$d` is a `$` token, which is
+/// needed to write the generated table macro's own metavariables.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_parameters {
+    (($d:tt) $raw_parameters: ident, $vis: vis $parameter_type: ident, $table: ident
+     $(, $option: ident $(= $option_value: expr)?)*;
+     $($variant:ident, $idx:expr, $name:expr, $field_name:ident, $default:expr, $string:expr $(, $column:ident = $value:expr)*;)*) => {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        $vis enum $parameter_type {
+            $($variant,)*
+        }
+
+        // `impl_all!` defines `__$raw_parameters_impl_default` locally, so it
+        // can't be reached through `$crate`.
+        $crate::paste::paste! {
+            macro_rules! $table {
+                ([<__ $raw_parameters _impl_default>]) => {
+                    [<__ $raw_parameters _impl_default>]! {
+                        $raw_parameters, $parameter_type;
+                        $($parameter_type::$variant, $idx, $name, $field_name, $default, $string $(, $column = $value)*;)*
+                    }
+                };
+                ($d macro:ident) => {
+                    $crate::$d macro! {
+                        $raw_parameters, $parameter_type;
+                        $($parameter_type::$variant, $idx, $name, $field_name, $default, $string $(, $column = $value)*;)*
+                    }
+                };
+            }
+        }
+
+        $crate::impl_all!($raw_parameters, $parameter_type, $table $(, $option $(= $option_value)?)*);
+        $table! {impl_debug}
     };
 }
@@ -0,0 +1,34 @@
+//! Versioning for saved parameters, shared by `impl_state!` and
+//! `impl_preset_serde!`. Plugins which rename or rescale parameters implement
+//! `PresetMigration` and pass `migrate_presets` to `impl_all!`, so presets and
+//! named chunks saved with an older version are updated as they load.
+use std::collections::HashMap;
+
+/// Updates the values of presets saved with an older version. Implement this
+/// on `$raw_parameters` and pass `migrate_presets` to `impl_all!`. Since
+/// `migrate` takes `&self`, it may depend on the instance's own fields.
+pub trait PresetMigration {
+    /// The version presets and named chunks are saved with. Ones saved before
+    /// a migration was added count as version 0.
+    const PRESET_VERSION: u32;
+
+    /// Transforms `values`, keyed by parameter name, which were saved with
+    /// `version`, before they are loaded. This is only called when `version`
+    /// is below `PRESET_VERSION`.
+    fn migrate(&self, version: u32, values: &mut HashMap<String, f32>);
+}
+
+/// Returns the value saved under `name`, or else under the first of `aliases`
+/// found, so presets saved before a parameter was renamed still load. Names
+/// are compared ignoring ASCII case, as in `parameter_by_name`.
+pub fn lookup(values: &HashMap<String, f32>, name: &str, aliases: &[&str]) -> Option<f32> {
+    let find = |name: &str| {
+        values.get(name).copied().or_else(|| {
+            values
+                .iter()
+                .find(|(saved, _)| saved.eq_ignore_ascii_case(name))
+                .map(|(_, &value)| value)
+        })
+    };
+    find(name).or_else(|| aliases.iter().find_map(|alias| find(alias)))
+}
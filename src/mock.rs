@@ -0,0 +1,88 @@
+//! A stand-in VST host for testing code built on the table macros without a
+//! real host. `host()` returns a `HostCallback` which records the calls the
+//! generated code makes, such as `begin_edit` and `end_edit`, and a handle to
+//! read them back. With this feature enabled the tables also generate
+//! `default_with_mock()`, which builds `$raw_parameters` around a mock host.
+
+use std::convert::TryFrom;
+use std::os::raw::c_void;
+use std::sync::Mutex;
+
+use vst::api::{consts::VST_MAGIC, AEffect};
+use vst::host::OpCode;
+use vst::plugin::HostCallback;
+
+/// A call made to a mock host.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HostEvent {
+    /// `automate(index, value)`
+    Automate(i32, f32),
+    /// `begin_edit(index)`
+    BeginEdit(i32),
+    /// `end_edit(index)`
+    EndEdit(i32),
+    /// `update_display()`
+    UpdateDisplay,
+}
+
+/// The state behind a mock host. `HostCallback` passes a pointer to this as
+/// its effect, so it starts with the `magic` field of an `AEffect`, which is
+/// all `HostCallback` reads from it.
+#[repr(C)]
+struct MockHost {
+    magic: i32,
+    events: Mutex<Vec<HostEvent>>,
+}
+
+/// A handle to the events recorded by a mock host from `host()`.
+#[derive(Clone, Copy)]
+pub struct MockHostHandle {
+    host: &'static MockHost,
+}
+
+impl MockHostHandle {
+    /// Returns every event recorded so far, oldest first.
+    pub fn events(&self) -> Vec<HostEvent> {
+        self.host.events.lock().unwrap().clone()
+    }
+
+    /// Returns every event recorded so far and clears them.
+    pub fn take_events(&self) -> Vec<HostEvent> {
+        std::mem::take(&mut *self.host.events.lock().unwrap())
+    }
+}
+
+/// Creates a mock host. The host's state is leaked, since the `HostCallback`
+/// may be copied anywhere, so this is only meant for tests.
+pub fn host() -> (HostCallback, MockHostHandle) {
+    let host: &'static MockHost = Box::leak(Box::new(MockHost {
+        magic: VST_MAGIC,
+        events: Mutex::new(Vec::new()),
+    }));
+    let effect = host as *const MockHost as *mut AEffect;
+    (
+        HostCallback::wrap(callback, effect),
+        MockHostHandle { host },
+    )
+}
+
+extern "C" fn callback(
+    effect: *mut AEffect,
+    opcode: i32,
+    index: i32,
+    _value: isize,
+    _ptr: *mut c_void,
+    opt: f32,
+) -> isize {
+    // Safety: `effect` is the leaked `MockHost` from `host`.
+    let host = unsafe { &*(effect as *const MockHost) };
+    let event = match OpCode::try_from(opcode) {
+        Ok(OpCode::Automate) => HostEvent::Automate(index, opt),
+        Ok(OpCode::BeginEdit) => HostEvent::BeginEdit(index),
+        Ok(OpCode::EndEdit) => HostEvent::EndEdit(index),
+        Ok(OpCode::UpdateDisplay) => HostEvent::UpdateDisplay,
+        _ => return 0,
+    };
+    host.events.lock().unwrap().push(event);
+    0
+}
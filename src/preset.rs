@@ -0,0 +1,69 @@
+//! Name-keyed preset serialization, used by `impl_preset_serde!`. Presets are
+//! stored as a JSON object from parameter name to normalized value.
+use std::collections::{BTreeMap, HashMap};
+
+/// The key under which presets record their `PresetMigration::PRESET_VERSION`.
+/// Presets from before a migration was added have no version, which is
+/// treated as version 0.
+pub const VERSION_KEY: &str = "#version";
+
+/// The error returned when preset data can't be read.
+#[derive(Debug)]
+pub enum PresetError {
+    Malformed(serde_json::Error),
+}
+
+impl std::fmt::Display for PresetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PresetError::Malformed(err) => write!(f, "malformed preset data: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PresetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PresetError::Malformed(err) => Some(err),
+        }
+    }
+}
+
+/// Collects `(name, normalized value, default)` triples into a map. JSON
+/// can't represent NaN or infinities, so those are saved as the default.
+fn finite_values<'a>(values: &[(&'a str, f32, f32)]) -> BTreeMap<&'a str, f32> {
+    values
+        .iter()
+        .map(|&(name, value, default)| (name, if value.is_finite() { value } else { default }))
+        .collect()
+}
+
+/// Serializes `(name, normalized value, default)` triples into preset data.
+pub fn serialize(values: &[(&str, f32, f32)]) -> Vec<u8> {
+    // Serializing a map of strings to floats can't fail.
+    serde_json::to_vec(&finite_values(values)).unwrap()
+}
+
+/// Serializes `(name, normalized value, default)` triples into a human
+/// readable JSON object, for debugging.
+pub fn to_json(values: &[(&str, f32, f32)]) -> String {
+    serde_json::to_string_pretty(&finite_values(values)).unwrap()
+}
+
+/// Reads preset data back into a map from parameter name to normalized value.
+pub fn deserialize(data: &[u8]) -> Result<HashMap<String, f32>, PresetError> {
+    serde_json::from_slice(data).map_err(PresetError::Malformed)
+}
+
+/// Reads a JSON object back into a map from parameter name to normalized value.
+pub fn from_json(json: &str) -> Result<HashMap<String, f32>, PresetError> {
+    serde_json::from_str(json).map_err(PresetError::Malformed)
+}
+
+/// Removes the version recorded under `VERSION_KEY` from loaded preset
+/// `values` and returns it, or 0 if there was none.
+pub fn take_version(values: &mut HashMap<String, f32>) -> u32 {
+    values
+        .remove(VERSION_KEY)
+        .map_or(0, |version| version as u32)
+}
@@ -0,0 +1,203 @@
+//! Binary chunk formats for `impl_state!`. Chunks from `write` are a
+//! little-endian `u32` version followed by one little-endian `f32` per
+//! parameter, in index order.
+//!
+//! Chunks from `write_named` are `[MAGIC][version][preset version][count]`
+//! followed by `count` `[name length][name][value]` entries, where the
+//! versions, count and name length are `u32`s, the name is UTF-8, and the
+//! value is an `f32`, all little-endian. Since values are keyed by name, these
+//! still load after parameters are added, removed, or reordered, and a
+//! `PresetMigration` can update them.
+//!
+//! Version 1 of the named format had no preset version, and stored a `u64`
+//! hash from `name_hash` in place of each name. These still load, but only
+//! values whose hash matches a known name are kept.
+
+use std::collections::HashMap;
+
+/// The chunk version written by `save_state`.
+pub const VERSION: u32 = 1;
+
+/// The first bytes of a chunk written by `save_named_state`.
+pub const MAGIC: [u8; 4] = *b"VSTN";
+
+/// The chunk version written by `save_named_state`.
+pub const NAMED_VERSION: u32 = 2;
+
+/// The error returned when a chunk can't be loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateError {
+    /// The chunk was too short to contain a version header.
+    MissingHeader,
+    /// The chunk didn't start with `MAGIC`.
+    BadMagic,
+    /// The chunk was saved with an unknown version.
+    UnsupportedVersion(u32),
+    /// The chunk held a different number of parameters than the table.
+    LengthMismatch { expected: usize, found: usize },
+    /// A named chunk ended early or had a name which wasn't UTF-8.
+    Malformed,
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::MissingHeader => write!(f, "state chunk is missing its version header"),
+            StateError::BadMagic => write!(f, "state chunk does not start with the expected magic"),
+            StateError::UnsupportedVersion(version) => {
+                write!(f, "unsupported state chunk version {}", version)
+            }
+            StateError::LengthMismatch { expected, found } => write!(
+                f,
+                "state chunk has {} bytes of parameters, expected {}",
+                found, expected
+            ),
+            StateError::Malformed => write!(f, "state chunk is malformed"),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// Writes normalized parameter values, in index order, into a chunk.
+pub fn write(values: impl Iterator<Item = f32>) -> Vec<u8> {
+    let mut data = VERSION.to_le_bytes().to_vec();
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data
+}
+
+/// Reads a chunk which should hold exactly `count` parameter values.
+pub fn read(data: &[u8], count: usize) -> Result<Vec<f32>, StateError> {
+    if data.len() < 4 {
+        return Err(StateError::MissingHeader);
+    }
+    let (header, values) = data.split_at(4);
+    let version = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    if version != VERSION {
+        return Err(StateError::UnsupportedVersion(version));
+    }
+    if values.len() != count * 4 {
+        return Err(StateError::LengthMismatch {
+            expected: count * 4,
+            found: values.len(),
+        });
+    }
+    Ok(values
+        .chunks_exact(4)
+        .map(|value| f32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+        .collect())
+}
+
+/// Writes `(name, normalized value)` pairs into a named chunk, recording
+/// `preset_version` for migrations.
+pub fn write_named<'a>(
+    preset_version: u32,
+    values: impl Iterator<Item = (&'a str, f32)>,
+) -> Vec<u8> {
+    let mut data = MAGIC.to_vec();
+    data.extend_from_slice(&NAMED_VERSION.to_le_bytes());
+    data.extend_from_slice(&preset_version.to_le_bytes());
+    // The count is filled in once the values have been written.
+    data.extend_from_slice(&0u32.to_le_bytes());
+    let mut count = 0u32;
+    for (name, value) in values {
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+        data.extend_from_slice(&value.to_le_bytes());
+        count += 1;
+    }
+    data[12..16].copy_from_slice(&count.to_le_bytes());
+    data
+}
+
+/// Reads a chunk written by `write_named`, returning its preset version and
+/// its values by name. Version 1 chunks only hold name hashes, so their names
+/// are recovered from `known_names`, and their preset version is 0.
+pub fn read_named(
+    data: &[u8],
+    known_names: &[&str],
+) -> Result<(u32, HashMap<String, f32>), StateError> {
+    let mut reader = Reader(data);
+    if reader.take(4).ok_or(StateError::MissingHeader)? != MAGIC {
+        return Err(StateError::BadMagic);
+    }
+    let version = reader.u32().ok_or(StateError::MissingHeader)?;
+    match version {
+        1 => read_hashed(reader, known_names),
+        NAMED_VERSION => {
+            let preset_version = reader.u32().ok_or(StateError::MissingHeader)?;
+            let count = reader.u32().ok_or(StateError::MissingHeader)?;
+            let mut values = HashMap::new();
+            for _ in 0..count {
+                let length = reader.u32().ok_or(StateError::Malformed)? as usize;
+                let name = reader.take(length).ok_or(StateError::Malformed)?;
+                let name = std::str::from_utf8(name).map_err(|_| StateError::Malformed)?;
+                let value = reader.f32().ok_or(StateError::Malformed)?;
+                values.insert(name.to_string(), value);
+            }
+            if !reader.0.is_empty() {
+                return Err(StateError::Malformed);
+            }
+            Ok((preset_version, values))
+        }
+        _ => Err(StateError::UnsupportedVersion(version)),
+    }
+}
+
+/// Reads the rest of a version 1 named chunk, after its version.
+fn read_hashed(
+    mut reader: Reader<'_>,
+    known_names: &[&str],
+) -> Result<(u32, HashMap<String, f32>), StateError> {
+    let count = reader.u32().ok_or(StateError::MissingHeader)? as usize;
+    // A count from a corrupt chunk can overflow on 32-bit targets.
+    let expected = count.checked_mul(12);
+    if expected != Some(reader.0.len()) {
+        return Err(StateError::LengthMismatch {
+            expected: expected.unwrap_or(usize::MAX),
+            found: reader.0.len(),
+        });
+    }
+    let mut values = HashMap::new();
+    while let (Some(hash), Some(value)) = (reader.u64(), reader.f32()) {
+        if let Some(name) = known_names
+            .iter()
+            .find(|&&name| crate::name_hash(name) == hash)
+        {
+            values.insert(name.to_string(), value);
+        }
+    }
+    Ok((0, values))
+}
+
+/// Reads little-endian values from the front of a chunk.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.0.len() < length {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Some(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.u32().map(f32::from_bits)
+    }
+}
@@ -0,0 +1,44 @@
+//! `proptest` strategies built from a parameter table, for property tests such
+//! as "`set` then `get` returns the same normalized value". `set_parameter`
+//! notifies the host, so this uses the `mock` feature's host:
+//!
+//! ```ignore
+//! proptest! {
+//!     #[test]
+//!     fn set_then_get((index, value) in parameter_values(RawParameters::descriptors())) {
+//!         let (raw, _host) = RawParameters::default_with_mock();
+//!         raw.set_parameter(index, value);
+//!         prop_assert!((raw.get_parameter(index) - value).abs() < 1e-6);
+//!     }
+//! }
+//! ```
+
+use proptest::prelude::*;
+
+use crate::{ParameterDescriptor, ParameterKind};
+
+/// Returns a strategy yielding `(index, value)` pairs, where `index` is the
+/// index of any of `descriptors` and `value` is a normalized value that
+/// parameter can hold exactly (see `normalized_value`).
+pub fn parameter_values(
+    descriptors: &'static [ParameterDescriptor],
+) -> impl Strategy<Value = (i32, f32)> {
+    proptest::sample::select(descriptors).prop_flat_map(|descriptor| {
+        let index = descriptor.index;
+        normalized_value(descriptor.kind, descriptor.steps).prop_map(move |value| (index, value))
+    })
+}
+
+/// Returns a strategy yielding normalized values in `[0, 1]` which a field of
+/// the given kind stores without rounding: any value for `Float`, 0 or 1 for
+/// `Bool`, and one of the `steps` for `Int`.
+pub fn normalized_value(kind: ParameterKind, steps: i32) -> BoxedStrategy<f32> {
+    match kind {
+        ParameterKind::Float => (0.0f32..=1.0).boxed(),
+        ParameterKind::Bool => prop_oneof![Just(0.0), Just(1.0)].boxed(),
+        ParameterKind::Int if steps > 1 => (0..steps)
+            .prop_map(move |step| step as f32 / (steps - 1) as f32)
+            .boxed(),
+        ParameterKind::Int => Just(0.0).boxed(),
+    }
+}
@@ -0,0 +1,414 @@
+//! The generated accessors, on the table shared by the integration tests.
+
+#[macro_use]
+mod common;
+
+use common::{ParameterType, RawParameters};
+use vst::plugin::PluginParameters;
+
+#[test]
+fn iter_all_yields_every_parameter_in_index_order() {
+    let all: Vec<_> = RawParameters::iter_all().collect();
+    assert_eq!(
+        all,
+        [
+            ParameterType::Volume,
+            ParameterType::Cutoff,
+            ParameterType::Meter,
+            ParameterType::Mode,
+        ]
+    );
+
+    let (params, _host) = RawParameters::default_with_mock();
+    for parameter in RawParameters::iter_all() {
+        params.set(1.0, parameter);
+    }
+    assert!(RawParameters::iter_all().all(|parameter| params.get(parameter) == 1.0));
+}
+
+#[test]
+fn denormalized_values_use_the_range_columns() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(
+        RawParameters::get_range(ParameterType::Cutoff),
+        (20.0, 20000.0)
+    );
+    assert_eq!(RawParameters::get_range(ParameterType::Volume), (0.0, 1.0));
+    assert_eq!(params.get_denormalized(ParameterType::Cutoff), 5015.0);
+
+    params.set_denormalized(20000.0, ParameterType::Cutoff);
+    assert_eq!(params.get(ParameterType::Cutoff), 1.0);
+    params.set_denormalized(0.25, ParameterType::Volume);
+    assert_eq!(params.get_denormalized(ParameterType::Volume), 0.25);
+}
+
+#[test]
+fn out_of_range_indices_are_an_error() {
+    use std::convert::TryFrom;
+    use vst_utils::ParameterIndexError;
+
+    assert_eq!(ParameterType::try_from(1), Ok(ParameterType::Cutoff));
+    assert_eq!(ParameterType::try_from(4), Err(ParameterIndexError(4)));
+    assert_eq!(
+        ParameterIndexError(-1).to_string(),
+        "no parameter has index -1"
+    );
+}
+
+#[test]
+fn state_chunks_round_trip_and_reject_other_layouts() {
+    use vst_utils::state::StateError;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.75, ParameterType::Cutoff);
+    let chunk = params.save_state();
+
+    let (loaded, _host) = RawParameters::default_with_mock();
+    loaded.load_state(&chunk).unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.75);
+
+    loaded.set(0.1, ParameterType::Cutoff);
+    assert_eq!(
+        loaded.load_state(&chunk[..chunk.len() - 4]),
+        Err(StateError::LengthMismatch {
+            expected: 16,
+            found: 12
+        })
+    );
+    assert_eq!(loaded.load_state(&[1, 0]), Err(StateError::MissingHeader));
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.1);
+}
+
+#[test]
+fn named_state_chunks_load_across_layouts() {
+    use vst_utils::{name_hash, state};
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.75, ParameterType::Cutoff);
+    let chunk = params.save_named_state();
+
+    let (loaded, _host) = RawParameters::default_with_mock();
+    loaded.load_named_state(&chunk).unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.75);
+
+    // Saved by a layout without "Volume" and with a since-removed "Resonance".
+    // Cutoff was still called "Freq" back then.
+    loaded.set(0.9, ParameterType::Volume);
+    let old = state::write_named(0, [("Freq", 0.3), ("Resonance", 0.8)].iter().copied());
+    loaded.load_named_state(&old).unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.3);
+    assert_eq!(loaded.get(ParameterType::Volume), 0.5);
+
+    // A version 1 chunk, which held name hashes, claiming far more values
+    // than it has.
+    let mut corrupt = state::MAGIC.to_vec();
+    corrupt.extend_from_slice(&1u32.to_le_bytes());
+    corrupt.extend_from_slice(&u32::MAX.to_le_bytes());
+    for (name, value) in [("Freq", 0.3f32), ("Resonance", 0.8)] {
+        corrupt.extend_from_slice(&name_hash(name).to_le_bytes());
+        corrupt.extend_from_slice(&value.to_le_bytes());
+    }
+    assert!(matches!(
+        loaded.load_named_state(&corrupt),
+        Err(state::StateError::LengthMismatch { found: 24, .. })
+    ));
+    assert_eq!(
+        loaded.load_named_state(&chunk[4..]),
+        Err(state::StateError::BadMagic)
+    );
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.3);
+}
+
+#[test]
+fn reset_to_defaults() {
+    let (params, _host) = RawParameters::default_with_mock();
+    for parameter in RawParameters::iter_all() {
+        params.set(0.9, parameter);
+    }
+    params.reset(ParameterType::Volume);
+    assert_eq!(params.get(ParameterType::Volume), 0.5);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.9);
+
+    params.reset_to_defaults();
+    let values: Vec<_> = RawParameters::iter_all().map(|p| params.get(p)).collect();
+    assert_eq!(values, [0.5, 0.25, 0.0, 0.0]);
+}
+
+#[test]
+fn gestures_bracket_unnotified_sets() {
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = RawParameters::default_with_mock();
+    params.begin_gesture(ParameterType::Cutoff);
+    params.set_without_notify(0.3, ParameterType::Cutoff);
+    params.set_without_notify(0.6, ParameterType::Cutoff);
+    params.end_gesture(ParameterType::Cutoff);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.6);
+    assert_eq!(host.take_events(), vec![BeginEdit(1), EndEdit(1)]);
+}
+
+#[test]
+fn notify_all_changed_updates_the_display_once() {
+    let (params, host) = RawParameters::default_with_mock();
+    params.notify_all_changed();
+    assert_eq!(
+        host.take_events(),
+        vec![vst_utils::mock::HostEvent::UpdateDisplay]
+    );
+}
+
+#[test]
+fn typed_accessors_wrap_get_and_set() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.volume(), 0.5);
+    params.set_cutoff(0.75);
+    assert_eq!(params.cutoff(), 0.75);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.75);
+}
+
+#[test]
+fn int_fields_store_the_step() {
+    use std::sync::atomic::Ordering;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.7, ParameterType::Mode);
+    assert_eq!(params.mode.load(Ordering::Relaxed), 2);
+    assert_eq!(params.get(ParameterType::Mode), 2.0 / 3.0);
+    params.set(1.0, ParameterType::Mode);
+    assert_eq!(params.mode.load(Ordering::Relaxed), 3);
+    assert_eq!(params.get(ParameterType::Mode), 1.0);
+}
+
+#[test]
+fn apply_reverses_the_parameters_conversion() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.apply(&common::Parameters {
+        volume: 0.1,
+        cutoff: 10010.0,
+        meter: 0.3,
+        mode: 2.0,
+    });
+    assert_eq!(params.get(ParameterType::Volume), 0.1);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.5);
+    assert_eq!(params.get_discrete(ParameterType::Mode), 2);
+
+    let parameters = common::Parameters::from(&params);
+    let (copy, _host) = RawParameters::default_with_mock();
+    copy.apply(&parameters);
+    for parameter in RawParameters::iter_all() {
+        assert_eq!(copy.get(parameter), params.get(parameter));
+    }
+}
+
+#[test]
+fn from_parameters_builds_without_notifying_the_host() {
+    let (host, events) = vst_utils::mock::host();
+    let params = RawParameters::from_parameters(
+        common::Parameters {
+            volume: 0.1,
+            cutoff: 10010.0,
+            meter: 0.3,
+            mode: 2.0,
+        },
+        host,
+    );
+    assert_eq!(params.get(ParameterType::Volume), 0.1);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.5);
+    assert_eq!(params.get_discrete(ParameterType::Mode), 2);
+    assert!(events.take_events().is_empty());
+}
+
+#[test]
+fn parameter_value_carries_the_text_and_unit() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.5, ParameterType::Cutoff);
+    assert_eq!(
+        params.get_parameter_value(ParameterType::Cutoff),
+        vst_utils::ParameterValue {
+            value: 10010.0,
+            text: "10.01".to_string(),
+            unit: "kHz".to_string(),
+        }
+    );
+}
+
+#[test]
+fn gesture_always_ends() {
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = RawParameters::default_with_mock();
+    host.take_events();
+    let drag = |values: &[f32]| -> Option<()> {
+        let gesture = params.gesture(ParameterType::Volume);
+        for &value in values {
+            if value > 1.0 {
+                return None;
+            }
+            gesture.set(value);
+        }
+        Some(())
+    };
+    assert_eq!(drag(&[0.1, 0.2]), Some(()));
+    assert_eq!(host.take_events(), vec![BeginEdit(0), EndEdit(0)]);
+    assert_eq!(drag(&[0.3, 2.0, 0.4]), None);
+    assert_eq!(host.take_events(), vec![BeginEdit(0), EndEdit(0)]);
+    assert_eq!(params.get(ParameterType::Volume), 0.3);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let gesture = params.gesture(ParameterType::Cutoff);
+        gesture.set(0.5);
+        panic!("interrupted drag");
+    }));
+    assert!(result.is_err());
+    assert_eq!(host.take_events(), vec![BeginEdit(1), EndEdit(1)]);
+}
+
+#[test]
+fn set_clamped_keeps_values_normalized() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_clamped(-0.0001, ParameterType::Volume);
+    assert_eq!(params.get(ParameterType::Volume), 0.0);
+    params.set_clamped(1.0001, ParameterType::Volume);
+    assert_eq!(params.get(ParameterType::Volume), 1.0);
+    params.set_clamped(0.25, ParameterType::Volume);
+    assert_eq!(params.get(ParameterType::Volume), 0.25);
+    params.set(1.5, ParameterType::Volume);
+    assert_eq!(params.get(ParameterType::Volume), 1.5);
+}
+
+#[cfg(feature = "clamp-params")]
+#[test]
+fn host_writes_are_clamped() {
+    use vst::plugin::PluginParameters;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_parameter(0, 1.0001);
+    assert_eq!(params.get(ParameterType::Volume), 1.0);
+    params.set_parameter(0, -0.0001);
+    assert_eq!(params.get(ParameterType::Volume), 0.0);
+}
+
+#[test]
+fn snapshot_reads_every_parameter() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.5, ParameterType::Cutoff);
+    params.set(2.0 / 3.0, ParameterType::Mode);
+    let snapshot = params.snapshot();
+    assert_eq!(snapshot.volume, 0.5);
+    assert_eq!(snapshot.cutoff, 10010.0);
+    assert_eq!(snapshot.meter, 0.0);
+    assert_eq!(snapshot.mode, 2.0);
+}
+
+#[test]
+fn set_from_gui_always_writes_and_notifies() {
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = RawParameters::default_with_mock();
+    host.take_events();
+    params.set_from_gui(0.5, ParameterType::Volume);
+    params.set_from_gui(0.75, ParameterType::Cutoff);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.75);
+    assert_eq!(
+        host.take_events(),
+        vec![
+            BeginEdit(0),
+            Automate(0, 0.5),
+            EndEdit(0),
+            BeginEdit(1),
+            Automate(1, 0.75),
+            EndEdit(1),
+        ]
+    );
+}
+
+#[test]
+fn get_plain_maps_through_the_range_and_curve() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.get_plain(ParameterType::Cutoff), 5015.0);
+    assert_eq!(params.get(ParameterType::Cutoff), 0.25);
+    params.set(1.0, ParameterType::Meter);
+    assert_eq!(params.get_plain(ParameterType::Meter), 1.0);
+    params.set(0.5, ParameterType::Meter);
+    assert_eq!(
+        params.get_plain(ParameterType::Meter),
+        vst_utils::Curve::Exp.denormalize(0.0, 1.0, 0.5)
+    );
+    assert!(params.get_plain(ParameterType::Meter) < 0.5);
+}
+
+#[test]
+fn snapshot_array_is_in_index_order() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_parameter(0, 0.1);
+    params.set_parameter(1, 0.2);
+    params.set_meter(0.3);
+    let values = params.snapshot_array();
+    assert_eq!(values.len(), RawParameters::PARAMETER_COUNT as usize);
+    for (index, &value) in values.iter().enumerate() {
+        assert_eq!(value, params.get_parameter(index as i32));
+    }
+}
+
+#[test]
+fn params_equal_within_epsilon() {
+    let (params, _host) = RawParameters::default_with_mock();
+    let saved = params.snapshot();
+    assert!(params.params_equal(&saved, 1.0e-6));
+    params.set(0.5000001, ParameterType::Volume);
+    assert!(params.params_equal(&saved, 1.0e-4));
+    params.set(0.9, ParameterType::Volume);
+    assert!(!params.params_equal(&saved, 1.0e-4));
+}
+
+#[test]
+fn denormalized_round_trips() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.8, ParameterType::Volume);
+    params.set(0.3, ParameterType::Cutoff);
+    let values = params.denormalized();
+    assert_eq!(
+        values.cutoff,
+        params.get_denormalized(ParameterType::Cutoff)
+    );
+
+    let (other, _host) = RawParameters::default_with_mock();
+    other.set_from_denormalized(&values);
+    assert_eq!(other.denormalized(), values);
+    assert!((other.get(ParameterType::Cutoff) - 0.3).abs() < 1.0e-6);
+    assert!((other.get(ParameterType::Volume) - 0.8).abs() < 1.0e-6);
+}
+
+#[test]
+fn sample_rate_keeps_smoothing_in_sync() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.sample_rate(), vst_utils::DEFAULT_SAMPLE_RATE);
+
+    let mut smoothed = vst_utils::SmoothedParam::new(params.sample_rate(), 10.0);
+    let mut doubled = vst_utils::SmoothedParam::new(96000.0, 10.0);
+    params.set_sample_rate(96000.0);
+    assert_eq!(params.sample_rate(), 96000.0);
+    smoothed.sync_sample_rate(params.sample_rate());
+    smoothed.set_target(1.0);
+    doubled.set_target(1.0);
+    for _ in 0..100 {
+        assert_eq!(smoothed.next(), doubled.next());
+    }
+}
+
+#[test]
+fn iter_values_yields_every_parameter() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_parameter(1, 0.75);
+    let values: Vec<_> = params.iter_values().collect();
+    assert_eq!(values.len(), RawParameters::PARAMETER_COUNT as usize);
+    assert_eq!(
+        values,
+        vec![
+            (0, "Volume", 0.5),
+            (1, "Cutoff", 0.75),
+            (2, "Meter", 0.0),
+            (3, "Mode", 0.0)
+        ]
+    );
+}
@@ -0,0 +1,197 @@
+//! The CLAP params extension, called the way a host would.
+
+use std::ffi::{c_char, c_void, CStr};
+
+use vst_utils::clap_adapter::clap_sys::events::{
+    clap_event_header, clap_event_param_value, clap_input_events, CLAP_CORE_EVENT_SPACE_ID,
+    CLAP_EVENT_PARAM_VALUE,
+};
+use vst_utils::clap_adapter::clap_sys::ext::params::{
+    clap_param_info, clap_plugin_params, CLAP_PARAM_IS_AUTOMATABLE, CLAP_PARAM_IS_READONLY,
+    CLAP_PARAM_IS_STEPPED,
+};
+use vst_utils::clap_adapter::clap_sys::plugin::clap_plugin;
+use vst_utils::clap_adapter::{params_extension, ClapPlugin};
+
+mod common;
+
+use common::{ParameterType, RawParameters};
+
+struct Plugin {
+    params: RawParameters,
+}
+
+impl ClapPlugin for Plugin {
+    type Params = RawParameters;
+
+    unsafe fn params<'a>(plugin: *const clap_plugin) -> &'a RawParameters {
+        &(*((*plugin).plugin_data as *const Plugin)).params
+    }
+}
+
+static PARAMS: clap_plugin_params = params_extension::<Plugin>();
+
+/// A `clap_plugin` with only `plugin_data` filled in, which is all the
+/// extension reads.
+fn clap_plugin(plugin: &Plugin) -> clap_plugin {
+    clap_plugin {
+        desc: std::ptr::null(),
+        plugin_data: plugin as *const Plugin as *mut c_void,
+        init: None,
+        destroy: None,
+        activate: None,
+        deactivate: None,
+        start_processing: None,
+        stop_processing: None,
+        reset: None,
+        process: None,
+        get_extension: None,
+        on_main_thread: None,
+    }
+}
+
+fn info(plugin: &clap_plugin, index: u32) -> Option<clap_param_info> {
+    let mut info = std::mem::MaybeUninit::uninit();
+    unsafe {
+        (PARAMS.get_info.unwrap())(plugin, index, info.as_mut_ptr()).then(|| info.assume_init())
+    }
+}
+
+fn value_to_text(plugin: &clap_plugin, id: u32, value: f64) -> Option<String> {
+    let mut buffer = [0 as c_char; 64];
+    let written =
+        unsafe { (PARAMS.value_to_text.unwrap())(plugin, id, value, buffer.as_mut_ptr(), 64) };
+    written.then(|| {
+        unsafe { CStr::from_ptr(buffer.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    })
+}
+
+fn value(plugin: &clap_plugin, id: u32) -> Option<f64> {
+    let mut value = 0.0;
+    let found = unsafe { (PARAMS.get_value.unwrap())(plugin, id, &mut value) };
+    found.then_some(value)
+}
+
+#[test]
+fn param_info_uses_the_table() {
+    let plugin = Plugin {
+        params: RawParameters::default_with_mock().0,
+    };
+    let plugin = clap_plugin(&plugin);
+    assert_eq!(unsafe { (PARAMS.count.unwrap())(&plugin) }, 4);
+
+    let cutoff = info(&plugin, 1).unwrap();
+    assert_eq!(cutoff.id, 1);
+    assert_eq!(
+        unsafe { CStr::from_ptr(cutoff.name.as_ptr()) }.to_str(),
+        Ok("Cutoff")
+    );
+    assert_eq!(
+        unsafe { CStr::from_ptr(cutoff.module.as_ptr()) }.to_str(),
+        Ok("Filter")
+    );
+    assert_eq!((cutoff.min_value, cutoff.max_value), (20.0, 20000.0));
+    assert!((cutoff.default_value - 5015.0).abs() < 1e-3);
+    assert_eq!(cutoff.flags, CLAP_PARAM_IS_AUTOMATABLE);
+
+    let meter = info(&plugin, 2).unwrap();
+    assert_eq!(
+        unsafe { CStr::from_ptr(meter.name.as_ptr()) }.to_str(),
+        Ok("Output Meter")
+    );
+    assert_eq!(meter.flags, CLAP_PARAM_IS_READONLY);
+    let mode = info(&plugin, 3).unwrap();
+    assert_eq!(mode.flags, CLAP_PARAM_IS_STEPPED);
+    assert_eq!((mode.min_value, mode.max_value), (0.0, 3.0));
+    assert!(info(&plugin, 4).is_none());
+}
+
+#[test]
+fn values_are_plain() {
+    let plugin = Plugin {
+        params: RawParameters::default_with_mock().0,
+    };
+    let clap = clap_plugin(&plugin);
+    plugin.params.set(0.5, ParameterType::Cutoff);
+    assert!((value(&clap, 1).unwrap() - 10010.0).abs() < 1e-3);
+    assert!(value(&clap, 7).is_none());
+
+    let mut text_value = 0.0;
+    let text = b"1000 Hz\0".as_ptr() as *const c_char;
+    assert!(unsafe { (PARAMS.text_to_value.unwrap())(&clap, 1, text, &mut text_value) });
+    assert!((text_value - 1000.0).abs() < 1e-2);
+}
+
+#[test]
+fn value_to_text_formats_the_given_value() {
+    let plugin = Plugin {
+        params: RawParameters::default_with_mock().0,
+    };
+    let clap = clap_plugin(&plugin);
+    plugin.params.set(0.5, ParameterType::Cutoff);
+    assert_eq!(
+        value_to_text(&clap, 1, 20000.0).as_deref(),
+        Some("20.00 kHz")
+    );
+    assert_eq!(value_to_text(&clap, 1, 440.0).as_deref(), Some("440 Hz"));
+    assert_eq!(value_to_text(&clap, 3, 2.0).as_deref(), Some("2"));
+    // Formatting another value leaves the parameter alone.
+    assert_eq!(plugin.params.get(ParameterType::Cutoff), 0.5);
+}
+
+#[test]
+fn flush_applies_param_value_events() {
+    let (params, host) = RawParameters::default_with_mock();
+    let plugin = Plugin { params };
+    let clap = clap_plugin(&plugin);
+    let events = [clap_event_param_value {
+        header: clap_event_header {
+            size: std::mem::size_of::<clap_event_param_value>() as u32,
+            time: 0,
+            space_id: CLAP_CORE_EVENT_SPACE_ID,
+            type_: CLAP_EVENT_PARAM_VALUE,
+            flags: 0,
+        },
+        param_id: 1,
+        cookie: std::ptr::null_mut(),
+        note_id: -1,
+        port_index: -1,
+        channel: -1,
+        key: -1,
+        value: 20000.0,
+    }];
+
+    unsafe extern "C" fn size(list: *const clap_input_events) -> u32 {
+        (*((*list).ctx as *const [clap_event_param_value; 1])).len() as u32
+    }
+    unsafe extern "C" fn get(
+        list: *const clap_input_events,
+        index: u32,
+    ) -> *const clap_event_header {
+        let events = &*((*list).ctx as *const [clap_event_param_value; 1]);
+        &events[index as usize].header
+    }
+    let list = clap_input_events {
+        ctx: &events as *const _ as *mut c_void,
+        size: Some(size),
+        get: Some(get),
+    };
+    unsafe { (PARAMS.flush.unwrap())(&clap, &list, std::ptr::null()) };
+    assert_eq!(plugin.params.get(ParameterType::Cutoff), 1.0);
+    // Changes from the host aren't sent back to it.
+    assert!(host.take_events().is_empty());
+}
+
+#[test]
+fn readonly_parameters_ignore_set_param_value() {
+    use vst_utils::clap_adapter::ClapParams;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    assert!(!params.set_param_value(2, 0.5));
+    assert_eq!(params.get(ParameterType::Meter), 0.0);
+    assert!(params.set_param_value(0, 0.5));
+    assert!(!params.set_param_value(4, 0.5));
+}
@@ -0,0 +1,75 @@
+//! A table shared by the integration tests.
+#![allow(dead_code)]
+
+use std::sync::atomic::AtomicI32;
+
+use vst::host::Host;
+use vst::plugin::HostCallback;
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+pub struct RawParameters {
+    pub volume: AtomicFloat,
+    pub cutoff: AtomicFloat,
+    pub meter: AtomicFloat,
+    /// The mode's step, rather than a normalized value.
+    pub mode: AtomicI32,
+    pub host: HostCallback,
+    pub sample_rate: AtomicFloat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Volume,
+    Cutoff,
+    Meter,
+    Mode,
+}
+
+/// The values DSP code uses, so the cutoff is in Hz and the mode is one of
+/// its steps.
+pub struct Parameters {
+    pub volume: f32,
+    pub cutoff: f32,
+    pub meter: f32,
+    pub mode: f32,
+}
+
+impl From<&RawParameters> for Parameters {
+    fn from(raw: &RawParameters) -> Self {
+        Parameters {
+            volume: raw.volume.get(),
+            cutoff: raw.get_denormalized(ParameterType::Cutoff),
+            meter: raw.get_denormalized(ParameterType::Meter),
+            mode: raw.get_discrete(ParameterType::Mode) as f32,
+        }
+    }
+}
+
+macro_rules! table {
+    ($macro:ident) => {
+        $macro! {
+            RawParameters, ParameterType;
+            ParameterType::Volume, 0, "Volume", volume, 0.5, make_strings_db,
+                mod_range = 0.25, link = Some(ParameterType::Meter);
+            ParameterType::Cutoff, 1, "Cutoff", cutoff, 0.25, make_strings_hz,
+                min = 20.0, max = 20000.0, group = "Filter", short_name = "Cut",
+                cc = Some(74), unit = "Hz", aliases = &["Freq"], display_step = 10.0,
+                parse = |text| parse_number(text).map(|hz| inv_lerp(20.0, 20000.0, hz));
+            ParameterType::Meter, 2, "Meter", meter, 0.0, make_strings_percent,
+                readonly = true, display_name = "Output Meter", curve = Curve::Exp;
+            ParameterType::Mode, 3, "Mode", mode, 0.0, |mode| make_strings_prec(mode, "", 0),
+                steps = 4, kind = ParameterKind::Int, automatable = false;
+        }
+    };
+}
+
+impl_all_with_info!(RawParameters, ParameterType, table, track_sample_rate);
+table! {assert_contiguous_indices}
+#[cfg(feature = "clap")]
+table! {impl_clap_params}
+table! {impl_set_from_parameters}
+table! {impl_debug}
+table! {impl_params_equal}
+table! {impl_parameters_default}
+table! {impl_denormalized_parameters}
@@ -0,0 +1,8 @@
+//! Tables which must fail to compile, checked with `trybuild`. After changing
+//! one of the error messages, regenerate the expected output with
+//! `TRYBUILD=overwrite cargo test --test compile_fail`.
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,40 @@
+use vst::host::Host;
+use vst::plugin::HostCallback;
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+pub struct RawParameters {
+    pub volume: AtomicFloat,
+    pub host: HostCallback,
+    pub dirty: DirtyFlags,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Volume,
+}
+
+pub struct Parameters {
+    pub volume: f32,
+}
+
+impl From<&RawParameters> for Parameters {
+    fn from(raw: &RawParameters) -> Self {
+        Parameters {
+            volume: raw.volume.get(),
+        }
+    }
+}
+
+macro_rules! table {
+    ($macro:ident) => {
+        $macro! {
+            RawParameters, ParameterType;
+            ParameterType::Volume, 64, "Volume", volume, 0.5, make_strings_db;
+        }
+    };
+}
+
+impl_all!(RawParameters, ParameterType, table, track_dirty);
+
+fn main() {}
@@ -0,0 +1,48 @@
+// Only the error from impl_assert_unique_indices is expected, not the
+// warning the duplicate also raises in TryFrom<i32>.
+#![allow(unreachable_patterns)]
+
+use vst::host::Host;
+use vst::plugin::HostCallback;
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+pub struct RawParameters {
+    pub volume: AtomicFloat,
+    pub pan: AtomicFloat,
+    pub host: HostCallback,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Volume,
+    Pan,
+}
+
+pub struct Parameters {
+    pub volume: f32,
+    pub pan: f32,
+}
+
+impl From<&RawParameters> for Parameters {
+    fn from(raw: &RawParameters) -> Self {
+        Parameters {
+            volume: raw.volume.get(),
+            pan: raw.pan.get(),
+        }
+    }
+}
+
+macro_rules! table {
+    ($macro:ident) => {
+        $macro! {
+            RawParameters, ParameterType;
+            ParameterType::Volume, 0, "Volume", volume, 0.5, make_strings_db;
+            ParameterType::Pan, 0, "Pan", pan, 0.5, make_strings_percent;
+        }
+    };
+}
+
+impl_all!(RawParameters, ParameterType, table);
+
+fn main() {}
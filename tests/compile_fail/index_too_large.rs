@@ -0,0 +1,39 @@
+use vst::host::Host;
+use vst::plugin::HostCallback;
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+pub struct RawParameters {
+    pub volume: AtomicFloat,
+    pub host: HostCallback,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Volume,
+}
+
+pub struct Parameters {
+    pub volume: f32,
+}
+
+impl From<&RawParameters> for Parameters {
+    fn from(raw: &RawParameters) -> Self {
+        Parameters {
+            volume: raw.volume.get(),
+        }
+    }
+}
+
+macro_rules! table {
+    ($macro:ident) => {
+        $macro! {
+            RawParameters, ParameterType;
+            ParameterType::Volume, 1000000, "Volume", volume, 0.5, make_strings_db;
+        }
+    };
+}
+
+impl_all!(RawParameters, ParameterType, table);
+
+fn main() {}
@@ -0,0 +1,49 @@
+//! Out-of-range index warnings from the `debug-params` feature.
+
+mod common;
+
+use std::sync::Mutex;
+
+use log::{Log, Metadata, Record};
+use vst::plugin::PluginParameters;
+
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() == log::Level::Warn {
+            WARNINGS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger;
+
+#[test]
+fn out_of_range_indices_are_logged() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+
+    let (params, _host) = common::RawParameters::default_with_mock();
+    assert_eq!(params.get_parameter(37), 0.0);
+    params.set_parameter(-1, 0.5);
+    assert!(!params.can_be_automated(4));
+    params.set_parameter(0, 0.75);
+
+    assert_eq!(
+        *WARNINGS.lock().unwrap(),
+        [
+            "get_parameter called with out-of-range parameter index 37",
+            "set_parameter called with out-of-range parameter index -1",
+            "can_be_automated called with out-of-range parameter index 4",
+        ]
+    );
+}
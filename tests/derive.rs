@@ -0,0 +1,66 @@
+//! `#[derive(PluginParameters)]`, which builds the table from attributes.
+
+use std::convert::TryFrom;
+
+use vst::host::Host;
+use vst::plugin::{HostCallback, PluginParameters};
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+#[macro_use]
+mod common;
+
+#[derive(PluginParameters)]
+#[param_type(DerivedType)]
+pub struct DerivedParameters {
+    #[param(index = 0, name = "Master Volume", default = 0.5, short_name = "Vol")]
+    pub master_volume: AtomicFloat,
+    #[param(
+        index = 1,
+        name = "Drive",
+        default = 0.25,
+        string = |x| if x < 0.5 { make_strings(x, "clean") } else { make_strings(x, "dirty") },
+        min = 0.0,
+        max = 2.0,
+    )]
+    pub drive: AtomicFloat,
+    pub host: HostCallback,
+}
+
+pub struct Parameters {
+    pub master_volume: f32,
+    pub drive: f32,
+}
+
+impl From<&DerivedParameters> for Parameters {
+    fn from(raw: &DerivedParameters) -> Self {
+        Parameters {
+            master_volume: raw.master_volume.get(),
+            drive: raw.get_denormalized(DerivedType::Drive),
+        }
+    }
+}
+
+#[test]
+fn derives_the_enum() {
+    assert!(DerivedType::try_from(0) == Ok(DerivedType::MasterVolume));
+    assert!(DerivedType::try_from(1) == Ok(DerivedType::Drive));
+    assert!(DerivedType::try_from(2).is_err());
+    assert_eq!(i32::from(DerivedType::Drive), 1);
+    assert_eq!(DerivedType::MasterVolume.to_string(), "Master Volume");
+    assert_eq!(format!("{:?}", DerivedType::Drive), "Drive(1)");
+}
+
+#[test]
+fn derives_the_impl_all_api() {
+    let (params, _host) = DerivedParameters::default_with_mock();
+    assert_eq!(DerivedParameters::PARAMETER_COUNT, 2);
+    assert_eq!(DerivedParameters::get_range(DerivedType::Drive), (0.0, 2.0));
+    assert_eq!(params.get_parameter_short_name(0), "Vol");
+    assert_eq!(params.get_parameter_name(1), "Drive");
+    assert_eq!(params.get_parameter(1), 0.25);
+    assert_eq!(params.get_parameter_label(1), "dirty");
+
+    params.set(0.125, DerivedType::Drive);
+    assert_eq!(params.get_parameter_label(1), "clean");
+}
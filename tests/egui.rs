@@ -0,0 +1,49 @@
+//! `egui_range`, rendered with a headless `egui::Context`.
+
+mod common;
+
+use common::{ParameterType, RawParameters};
+use egui::{CentralPanel, Context, Event, Modifiers, PointerButton, RawInput, Rect, Slider};
+
+/// Renders a slider for the parameter, and returns the slider's rect.
+fn slider(ctx: &Context, input: RawInput, params: &RawParameters, value: &mut f32) -> Rect {
+    let mut rect = Rect::NOTHING;
+    let _ = ctx.run(input, |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            rect = ui
+                .add(Slider::new(value, params.egui_range(ParameterType::Cutoff)).show_value(false))
+                .rect;
+        });
+    });
+    rect
+}
+
+#[test]
+fn sliders_use_the_table_ranges() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.egui_range(ParameterType::Cutoff), 20.0..=20000.0);
+    assert_eq!(params.egui_range(ParameterType::Volume), 0.0..=1.0);
+
+    // Clicking the right end of the slider sets it to the top of its range.
+    let ctx = Context::default();
+    let mut cutoff = 5015.0;
+    let rect = slider(&ctx, RawInput::default(), &params, &mut cutoff);
+    let pos = rect.right_center();
+    let click = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    for events in [
+        vec![Event::PointerMoved(pos), click(true)],
+        vec![click(false)],
+    ] {
+        let input = RawInput {
+            events,
+            ..RawInput::default()
+        };
+        slider(&ctx, input, &params, &mut cutoff);
+    }
+    assert_eq!(cutoff, 20000.0);
+}
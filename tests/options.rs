@@ -0,0 +1,376 @@
+//! The options `impl_all!` takes after the table, which add fields and
+//! functions to `$raw_parameters`.
+
+mod common;
+
+use vst::host::Host;
+use vst::plugin::{HostCallback, PluginParameters};
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+pub struct RawParameters {
+    pub gain: AtomicFloat,
+    pub mix: AtomicFloat,
+    pub host: HostCallback,
+    pub dirty: DirtyFlags,
+    pub generations: Generations,
+    pub on_change: ChangeCallback<RawParameters, ParameterType>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterType {
+    Gain,
+    Mix,
+}
+
+pub struct Parameters {
+    pub gain: f32,
+    pub mix: f32,
+}
+
+impl From<&RawParameters> for Parameters {
+    fn from(raw: &RawParameters) -> Self {
+        Parameters {
+            gain: raw.gain.get(),
+            mix: raw.mix.get(),
+        }
+    }
+}
+
+impl From<&Parameters> for RawParameters {
+    fn from(parameters: &Parameters) -> Self {
+        RawParameters {
+            gain: AtomicFloat::new(parameters.gain),
+            mix: AtomicFloat::new(parameters.mix),
+            host: HostCallback::default(),
+            dirty: DirtyFlags::new(),
+            generations: Generations::new(2),
+            on_change: ChangeCallback::new(),
+        }
+    }
+}
+
+macro_rules! table {
+    ($macro:ident) => {
+        $macro! {
+            RawParameters, ParameterType;
+            ParameterType::Gain, 0, "Gain", gain, 0.5, make_strings_db;
+            ParameterType::Mix, 1, "Mix", mix, 1.0, |mix, params: &Parameters| {
+                if params.gain == 0.0 {
+                    ("Muted".to_string(), "".to_string())
+                } else {
+                    make_strings_percent(mix)
+                }
+            };
+        }
+    };
+}
+
+impl_all!(
+    RawParameters,
+    ParameterType,
+    table,
+    echo_epsilon = 0.01,
+    max_params = 16,
+    track_dirty,
+    track_generations,
+    on_change
+);
+table! {impl_assert_parameters_roundtrip}
+
+#[test]
+fn echo_epsilon_ignores_nearby_values() {
+    assert_eq!(RawParameters::ECHO_EPSILON, 0.01);
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_parameter(0, 0.505);
+    assert_eq!(params.get(ParameterType::Gain), 0.5);
+    params.set_parameter(0, 0.52);
+    assert_eq!(params.get(ParameterType::Gain), 0.52);
+}
+
+#[test]
+fn max_params_overrides_the_default_cap() {
+    assert_eq!(RawParameters::MAX_PARAMS, 16);
+    assert_eq!(sparse::RawParameters::MAX_PARAMS, vst_utils::MAX_PARAMS);
+}
+
+#[test]
+fn parameters_roundtrip() {
+    RawParameters::assert_parameters_roundtrip();
+}
+
+#[test]
+fn formatters_can_read_other_parameters() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.get_parameter_text(1), "100");
+    params.set(0.0, ParameterType::Gain);
+    assert_eq!(params.get_parameter_text(1), "Muted");
+}
+
+#[test]
+fn generation_bumps_on_unchanged_writes() {
+    let (params, _host) = RawParameters::default_with_mock();
+    let seen = params.generation(ParameterType::Gain);
+    params.set(0.5, ParameterType::Gain);
+    assert_eq!(params.generation(ParameterType::Gain), seen + 1);
+    params.set(0.5, ParameterType::Gain);
+    assert_eq!(params.generation(ParameterType::Gain), seen + 2);
+    assert!(params.parameter_changed_since(ParameterType::Gain, seen));
+    assert!(!params.parameter_changed_since(ParameterType::Mix, 0));
+}
+
+#[test]
+fn on_change_is_per_instance() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static GAIN_CHANGES: AtomicUsize = AtomicUsize::new(0);
+    fn count_gain(_: &RawParameters, parameter: ParameterType) {
+        assert_eq!(parameter, ParameterType::Gain);
+        GAIN_CHANGES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    let (first, _host) = RawParameters::default_with_mock();
+    let (second, _host) = RawParameters::default_with_mock();
+    first.set_on_change(Some(count_gain));
+    first.set(0.25, ParameterType::Gain);
+    second.set(0.75, ParameterType::Gain);
+    assert_eq!(GAIN_CHANGES.load(Ordering::SeqCst), 1);
+
+    // Host writes call it too, unless they are ignored as echoes.
+    first.set_parameter(0, 0.255);
+    first.set_parameter(0, 0.5);
+    assert_eq!(GAIN_CHANGES.load(Ordering::SeqCst), 2);
+
+    first.set_on_change(None);
+    first.set(0.75, ParameterType::Gain);
+    assert_eq!(GAIN_CHANGES.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn take_dirty_reports_each_write_once() {
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.take_dirty(), 0);
+    params.set(0.25, ParameterType::Mix);
+    assert_eq!(params.take_dirty(), 0b10);
+    assert_eq!(params.take_dirty(), 0);
+
+    params.set_parameter(0, 0.75);
+    params.set(0.75, ParameterType::Mix);
+    assert_eq!(params.take_dirty(), 0b11);
+    // Echoes aren't written, so they don't mark the parameter.
+    params.set_parameter(0, 0.755);
+    assert_eq!(params.take_dirty(), 0);
+}
+
+/// A table with a gap in its indices, so per-index state must be sized by
+/// `INDEX_LIMIT` rather than `PARAMETER_COUNT`.
+mod sparse {
+    use super::*;
+
+    pub struct RawParameters {
+        pub low: AtomicFloat,
+        pub high: AtomicFloat,
+        pub host: HostCallback,
+        pub touch: TouchFlags,
+        pub throttle: EditThrottle,
+        pub generations: Generations,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ParameterType {
+        Low,
+        High,
+    }
+
+    pub struct Parameters {
+        pub low: f32,
+        pub high: f32,
+    }
+
+    impl From<&RawParameters> for Parameters {
+        fn from(raw: &RawParameters) -> Self {
+            Parameters {
+                low: raw.low.get(),
+                high: raw.high.get(),
+            }
+        }
+    }
+
+    macro_rules! table {
+        ($macro:ident) => {
+            $macro! {
+                RawParameters, ParameterType;
+                ParameterType::Low, 0, "Low", low, 0.5, make_strings_percent;
+                ParameterType::High, 5, "High", high, 0.5, make_strings_percent;
+            }
+        };
+    }
+
+    impl_all!(
+        RawParameters,
+        ParameterType,
+        table,
+        track_touch,
+        throttle_edits,
+        track_generations
+    );
+}
+
+#[test]
+fn touch_sends_one_edit_per_pass() {
+    use sparse::ParameterType::{High, Low};
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = sparse::RawParameters::default_with_mock();
+    // Passes without writes send nothing.
+    params.touch(Low);
+    params.untouch(Low);
+    assert_eq!(host.take_events(), vec![]);
+
+    params.touch(Low);
+    params.set(0.1, Low);
+    params.set(0.2, Low);
+    params.set(0.3, Low);
+    assert_eq!(host.take_events(), vec![BeginEdit(0)]);
+    params.untouch(Low);
+    assert_eq!(host.take_events(), vec![EndEdit(0)]);
+    assert_eq!(params.get(Low), 0.3);
+
+    // Indices past PARAMETER_COUNT are tracked too.
+    params.touch(High);
+    params.set(0.4, High);
+    params.set(0.5, High);
+    params.untouch(High);
+    assert_eq!(host.take_events(), vec![BeginEdit(5), EndEdit(5)]);
+
+    // Untouched parameters still send an edit per write.
+    params.set(0.6, Low);
+    assert_eq!(host.take_events(), vec![BeginEdit(0), EndEdit(0)]);
+}
+
+#[test]
+fn throttle_sends_one_edit_per_interval() {
+    use sparse::ParameterType::{High, Low};
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = sparse::RawParameters::default_with_mock();
+    params.set(0.1, High);
+    params.set(0.2, High);
+    params.set(0.3, Low);
+    assert_eq!(
+        host.take_events(),
+        vec![BeginEdit(5), EndEdit(5), BeginEdit(0), EndEdit(0)]
+    );
+    assert_eq!(params.get(High), 0.2);
+
+    std::thread::sleep(std::time::Duration::from_millis(EDIT_THROTTLE_MS + 10));
+    params.set(0.4, High);
+    assert_eq!(host.take_events(), vec![BeginEdit(5), EndEdit(5)]);
+}
+
+#[test]
+fn reset_with_gesture_is_one_tracked_edit() {
+    use vst_utils::mock::HostEvent::*;
+
+    let (params, host) = RawParameters::default_with_mock();
+    params.gain.set(0.9);
+    params.reset_with_gesture(ParameterType::Gain);
+    assert_eq!(host.take_events(), vec![BeginEdit(0), EndEdit(0)]);
+    assert_eq!(params.get(ParameterType::Gain), 0.5);
+    assert_eq!(params.take_dirty(), 0b01);
+}
+
+/// The same table twice, once with the default `get_ref` and once with
+/// `jump_table`. Rows aren't in index order, so the jump table can't just
+/// follow them.
+macro_rules! get_ref_table {
+    (($d:tt) $module:ident [$($option:ident)*]) => {
+        mod $module {
+            use super::*;
+
+            pub struct RawParameters {
+                pub a: AtomicFloat,
+                pub b: AtomicFloat,
+                pub c: AtomicFloat,
+                pub d: AtomicFloat,
+                pub host: HostCallback,
+            }
+
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub enum ParameterType {
+                A,
+                B,
+                C,
+                D,
+            }
+
+            pub struct Parameters {
+                pub a: f32,
+                pub b: f32,
+                pub c: f32,
+                pub d: f32,
+            }
+
+            impl From<&RawParameters> for Parameters {
+                fn from(raw: &RawParameters) -> Self {
+                    Parameters {
+                        a: raw.a.get(),
+                        b: raw.b.get(),
+                        c: raw.c.get(),
+                        d: raw.d.get(),
+                    }
+                }
+            }
+
+            macro_rules! table {
+                ($d macro:ident) => {
+                    $d macro! {
+                        RawParameters, ParameterType;
+                        ParameterType::C, 2, "C", c, 0.3, make_strings_percent;
+                        ParameterType::A, 0, "A", a, 0.1, make_strings_percent;
+                        ParameterType::D, 3, "D", d, 0.4, make_strings_percent;
+                        ParameterType::B, 1, "B", b, 0.2, make_strings_percent;
+                    }
+                };
+            }
+
+            impl_all!(RawParameters, ParameterType, table $(, $option)*);
+        }
+    };
+}
+
+get_ref_table! {($) matched []}
+get_ref_table! {($) jump [jump_table]}
+
+#[test]
+fn jump_table_matches_get_ref_for_every_index() {
+    use std::convert::TryFrom;
+
+    let (matched, _host) = matched::RawParameters::default_with_mock();
+    let (jump, _host) = jump::RawParameters::default_with_mock();
+    for index in matched::RawParameters::index_range() {
+        assert_eq!(jump.get_parameter(index), matched.get_parameter(index));
+        let value = 0.9 - index as f32 / 10.0;
+        matched.set_parameter(index, value);
+        jump.set_parameter(index, value);
+    }
+    for index in matched::RawParameters::index_range() {
+        let expected = matched.get(matched::ParameterType::try_from(index).unwrap());
+        assert_eq!(
+            jump.get(jump::ParameterType::try_from(index).unwrap()),
+            expected
+        );
+        assert_eq!(jump.get_parameter(index), matched.get_parameter(index));
+    }
+}
+
+#[test]
+fn generations_with_sparse_indices() {
+    use sparse::ParameterType::{High, Low};
+    let (params, _host) = sparse::RawParameters::default_with_mock();
+    let seen = params.generation(High);
+    params.set(0.1, High);
+    assert!(params.parameter_changed_since(High, seen));
+    assert_eq!(params.generation(High), seen + 1);
+    assert_eq!(params.generation(Low), 0);
+}
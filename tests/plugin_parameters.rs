@@ -0,0 +1,215 @@
+//! `impl_plugin_parameters!` used on its own, without a table.
+
+use std::convert::TryFrom;
+
+use vst::plugin::PluginParameters;
+use vst::util::AtomicFloat;
+use vst_utils::*;
+
+struct RawParameters {
+    volume: AtomicFloat,
+}
+
+#[derive(Clone, Copy)]
+enum ParameterType {
+    Volume,
+}
+
+impl TryFrom<i32> for ParameterType {
+    type Error = ();
+    fn try_from(index: i32) -> Result<Self, ()> {
+        match index {
+            0 => Ok(ParameterType::Volume),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Volume")
+    }
+}
+
+impl RawParameters {
+    fn get(&self, _parameter: ParameterType) -> f32 {
+        self.volume.get()
+    }
+
+    fn set(&self, value: f32, _parameter: ParameterType) {
+        self.volume.set(value)
+    }
+
+    fn get_strings(&self, _parameter: ParameterType) -> (String, String) {
+        make_strings_percent(self.volume.get())
+    }
+}
+
+impl_plugin_parameters!(RawParameters, ParameterType);
+
+#[test]
+fn without_table_methods() {
+    let params = RawParameters {
+        volume: AtomicFloat::new(0.5),
+    };
+    assert_eq!(params.get_parameter_name(0), "Volume");
+    assert_eq!(params.get_parameter_text(0), "50");
+    assert!(params.can_be_automated(0));
+    assert!(!params.can_be_automated(1));
+    assert!(!params.string_to_parameter(0, "25".to_string()));
+
+    params.set_parameter(0, 0.25);
+    assert_eq!(params.get_parameter(0), 0.25);
+}
+
+/// `impl_get_set!` on its own, given only the baseline `get_ref`.
+mod get_set_without_table {
+    use vst::host::Host;
+    use vst::plugin::HostCallback;
+    use vst::util::AtomicFloat;
+    use vst_utils::*;
+
+    pub struct RawParameters {
+        pub volume: AtomicFloat,
+        pub host: HostCallback,
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum ParameterType {
+        Volume,
+    }
+
+    impl From<ParameterType> for i32 {
+        fn from(_parameter: ParameterType) -> i32 {
+            0
+        }
+    }
+
+    impl RawParameters {
+        fn get_ref(&self, _parameter: ParameterType) -> &AtomicFloat {
+            &self.volume
+        }
+    }
+
+    impl_get_set!(RawParameters, ParameterType);
+
+    #[test]
+    fn get_set_without_table() {
+        let params = RawParameters {
+            volume: AtomicFloat::new(0.5),
+            host: vst_utils::mock::host().0,
+        };
+        params.set(0.25, ParameterType::Volume);
+        assert_eq!(params.get(ParameterType::Volume), 0.25);
+        params.set_without_notify(0.75, ParameterType::Volume);
+        assert_eq!(params.volume.get(), 0.75);
+    }
+}
+
+#[macro_use]
+mod common;
+
+#[test]
+fn text_to_value_only_parses() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    let cutoff = common::ParameterType::Cutoff;
+    assert_eq!(params.text_to_value(cutoff, "abc dB"), None);
+    assert_eq!(params.text_to_value(cutoff, ""), None);
+    assert_eq!(params.text_to_value(cutoff, "20 Hz"), Some(0.0));
+    assert_eq!(params.get_parameter(1), 0.25);
+    // Volume has no `parse` column.
+    assert_eq!(
+        params.text_to_value(common::ParameterType::Volume, "0.5"),
+        None
+    );
+
+    assert!(!params.string_to_parameter(1, "abc dB".to_string()));
+    assert_eq!(params.get_parameter(1), 0.25);
+    assert!(params.string_to_parameter(1, "20000 Hz".to_string()));
+    assert_eq!(params.get_parameter(1), 1.0);
+    assert!(!params.string_to_parameter(4, "0.25".to_string()));
+}
+
+#[test]
+fn automatable_column() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    assert!(params.can_be_automated(0));
+    assert!(params.can_be_automated(1));
+    assert!(!params.can_be_automated(2));
+    assert!(!params.can_be_automated(3));
+    assert!(!params.can_be_automated(4));
+}
+
+#[test]
+fn short_name_column() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    assert_eq!(params.get_parameter_name(1), "Cutoff");
+    assert_eq!(params.get_parameter_short_name(1), "Cut");
+    assert_eq!(params.get_parameter_name(0), "Volume");
+    assert_eq!(params.get_parameter_short_name(0), "Volume");
+}
+
+#[test]
+fn echo_epsilon_defaults_to_exact_echoes() {
+    use common::{ParameterType, RawParameters};
+
+    assert_eq!(RawParameters::ECHO_EPSILON, 0.0);
+    let (params, _host) = common::RawParameters::default_with_mock();
+    params.set_parameter(0, 0.5001);
+    assert_eq!(params.get(ParameterType::Volume), 0.5001);
+}
+
+#[test]
+fn set_many_counts_applied_writes() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    // Index 0 already holds 0.5, index 2 is readonly and index 7 is out of
+    // range.
+    let applied = params.set_many(&[(0, 0.5), (1, 0.75), (2, 1.0), (7, 0.5), (0, 0.25)]);
+    assert_eq!(applied, 2);
+    assert_eq!(params.get_parameter(0), 0.25);
+    assert_eq!(params.get_parameter(1), 0.75);
+    assert_eq!(params.get_parameter(2), 0.0);
+}
+
+#[test]
+fn readonly_ignores_the_host() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    params.set_parameter(2, 0.8);
+    assert_eq!(params.get_parameter(2), 0.0);
+    assert!(!params.string_to_parameter(2, "80".to_string()));
+
+    params.set_meter(0.6);
+    assert_eq!(params.get_parameter(2), 0.6);
+    params.set_parameter(2, 0.8);
+    assert_eq!(params.get_parameter(2), 0.6);
+}
+
+#[test]
+fn corrupt_values_are_clamped_for_display() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    params.cutoff.set(3.4e38);
+    assert_eq!(params.get_parameter_text(1), "20.00");
+    assert_eq!(params.get_parameter_label(1), "kHz");
+    params.cutoff.set(-1.0);
+    assert_eq!(params.get_parameter_text(1), "20");
+    params.cutoff.set(f32::NAN);
+    assert_eq!(params.get_parameter_text(1), "20");
+}
+
+#[test]
+fn text_parts_match_the_text_and_label() {
+    let (params, _host) = common::RawParameters::default_with_mock();
+    params.set_parameter(1, 0.5);
+    assert_eq!(
+        params.get_parameter_text_parts(1),
+        (params.get_parameter_text(1), params.get_parameter_label(1))
+    );
+    assert_eq!(
+        params.get_parameter_text_parts(1),
+        ("10.01".to_string(), "kHz".to_string())
+    );
+    assert_eq!(
+        params.get_parameter_text_parts(7),
+        ("".to_string(), "".to_string())
+    );
+}
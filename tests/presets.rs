@@ -0,0 +1,244 @@
+//! Presets and named chunks, and migrating them between versions.
+
+#[macro_use]
+mod common;
+
+use common::{ParameterType, RawParameters};
+use vst_utils::impl_preset_serde;
+
+table! {impl_preset_serde}
+
+#[test]
+fn presets_round_trip_by_name() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.75, ParameterType::Cutoff);
+    let preset = params.to_preset();
+
+    let (loaded, _host) = RawParameters::default_with_mock();
+    loaded.from_preset(&preset).unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.75);
+    assert_eq!(loaded.get(ParameterType::Volume), 0.5);
+
+    loaded
+        .from_preset(br#"{"Volume": 0.1, "Removed": 0.9}"#)
+        .unwrap();
+    assert_eq!(loaded.get(ParameterType::Volume), 0.1);
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.75);
+    assert!(loaded.from_preset(b"not json").is_err());
+}
+
+#[test]
+fn non_finite_values_are_saved_as_the_default() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(f32::NAN, ParameterType::Volume);
+    params.set(f32::INFINITY, ParameterType::Cutoff);
+    let preset = params.to_preset();
+
+    let (loaded, _host) = RawParameters::default_with_mock();
+    loaded.set(0.9, ParameterType::Volume);
+    loaded.from_preset(&preset).unwrap();
+    assert_eq!(loaded.get(ParameterType::Volume), 0.5);
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.25);
+}
+
+#[test]
+fn json_dumps_round_trip_by_name() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.75, ParameterType::Cutoff);
+    params.set(f32::NAN, ParameterType::Volume);
+    let json = params.to_json();
+    assert!(json.contains(r#""Cutoff": 0.75"#));
+    assert!(json.contains(r#""Volume": 0.5"#));
+
+    let (loaded, _host) = RawParameters::default_with_mock();
+    loaded
+        .from_json(r#"{"Cutoff": 0.5, "Removed": 0.9}"#)
+        .unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.5);
+    assert_eq!(loaded.get(ParameterType::Volume), 0.5);
+    loaded.from_json(&json).unwrap();
+    assert_eq!(loaded.get(ParameterType::Cutoff), 0.75);
+}
+
+#[test]
+fn aliases_load_presets_saved_under_an_old_name() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.from_json(r#"{"Freq": 0.2}"#).unwrap();
+    assert_eq!(params.get(ParameterType::Cutoff), 0.2);
+    // The current name wins over an alias.
+    params.from_json(r#"{"Freq": 0.3, "Cutoff": 0.4}"#).unwrap();
+    assert_eq!(params.get(ParameterType::Cutoff), 0.4);
+    assert!(params.to_json().contains("Cutoff"));
+    assert!(!params.to_json().contains("Freq"));
+}
+
+#[test]
+fn parameters_default_matches_a_loaded_default_preset() {
+    let (defaults, _host) = RawParameters::default_with_mock();
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.9, ParameterType::Volume);
+    params.set(0.9, ParameterType::Cutoff);
+    params.set(0.9, ParameterType::Mode);
+    params.from_preset(&defaults.to_preset()).unwrap();
+
+    let expected = common::Parameters::default();
+    let loaded = common::Parameters::from(&params);
+    assert_eq!(loaded.volume, expected.volume);
+    assert_eq!(loaded.cutoff, expected.cutoff);
+    assert_eq!(loaded.meter, expected.meter);
+    assert_eq!(loaded.mode, expected.mode);
+    assert!(expected.cutoff > 20.0 && expected.cutoff < 20000.0);
+}
+
+/// A table which split the old "Cutoff" parameter into a low and a high
+/// cutoff in version 1.
+mod split {
+    use std::collections::HashMap;
+
+    use vst::host::Host;
+    use vst::plugin::HostCallback;
+    use vst::util::AtomicFloat;
+    use vst_utils::migration::PresetMigration;
+    use vst_utils::*;
+
+    pub struct RawParameters {
+        pub low_cutoff: AtomicFloat,
+        pub high_cutoff: AtomicFloat,
+        pub host: HostCallback,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ParameterType {
+        LowCutoff,
+        HighCutoff,
+    }
+
+    pub struct Parameters {
+        pub low_cutoff: f32,
+        pub high_cutoff: f32,
+    }
+
+    impl From<&RawParameters> for Parameters {
+        fn from(raw: &RawParameters) -> Self {
+            Parameters {
+                low_cutoff: raw.low_cutoff.get(),
+                high_cutoff: raw.high_cutoff.get(),
+            }
+        }
+    }
+
+    macro_rules! table {
+        ($macro:ident) => {
+            $macro! {
+                RawParameters, ParameterType;
+                ParameterType::LowCutoff, 0, "Low Cutoff", low_cutoff, 0.0, make_strings_percent;
+                ParameterType::HighCutoff, 1, "High Cutoff", high_cutoff, 1.0, make_strings_percent;
+            }
+        };
+    }
+
+    impl PresetMigration for RawParameters {
+        const PRESET_VERSION: u32 = 1;
+
+        fn migrate(&self, version: u32, values: &mut HashMap<String, f32>) {
+            assert_eq!(version, 0);
+            if let Some(value) = values.remove("Cutoff") {
+                values.insert("Low Cutoff".to_string(), value);
+                values.insert("High Cutoff".to_string(), value);
+            }
+        }
+    }
+
+    impl_all!(RawParameters, ParameterType, table, migrate_presets);
+    table! {impl_preset_serde}
+}
+
+/// Builds a version 1 named chunk, which stored name hashes.
+fn hashed_chunk(values: &[(&str, f32)]) -> Vec<u8> {
+    let mut data = b"VSTN".to_vec();
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for &(name, value) in values {
+        data.extend_from_slice(&vst_utils::name_hash(name).to_le_bytes());
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data
+}
+
+/// Builds a version 2 named chunk saved with preset version 0.
+fn unversioned_chunk(name: &str, value: f32) -> Vec<u8> {
+    let mut data = b"VSTN".to_vec();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(&value.to_le_bytes());
+    data
+}
+
+#[test]
+fn old_presets_are_migrated() {
+    use split::ParameterType::{HighCutoff, LowCutoff};
+
+    let (params, _host) = split::RawParameters::default_with_mock();
+    params.from_json(r#"{"Cutoff": 0.5}"#).unwrap();
+    assert_eq!(params.get(LowCutoff), 0.5);
+    assert_eq!(params.get(HighCutoff), 0.5);
+    assert!(params.to_json().contains(r##""#version": 1.0"##));
+
+    // Current presets aren't migrated again.
+    params
+        .from_json(r##"{"Cutoff": 0.75, "#version": 1.0}"##)
+        .unwrap();
+    assert_eq!(params.get(LowCutoff), 0.5);
+    params.from_preset(&params.to_preset()).unwrap();
+    assert_eq!(params.get(HighCutoff), 0.5);
+}
+
+#[test]
+fn named_chunks_record_their_version_and_are_migrated() {
+    use split::ParameterType::{HighCutoff, LowCutoff};
+
+    let (params, _host) = split::RawParameters::default_with_mock();
+    let (version, _) = vst_utils::state::read_named(&params.save_named_state(), &[]).unwrap();
+    assert_eq!(version, 1);
+    params
+        .load_named_state(&unversioned_chunk("Cutoff", 0.25))
+        .unwrap();
+    assert_eq!(params.get(LowCutoff), 0.25);
+    assert_eq!(params.get(HighCutoff), 0.25);
+
+    // Tables without `migrate_presets` save version 0.
+    let (unmigrated, _host) = RawParameters::default_with_mock();
+    let (version, _) = vst_utils::state::read_named(&unmigrated.save_named_state(), &[]).unwrap();
+    assert_eq!(version, 0);
+}
+
+#[test]
+fn hashed_named_chunks_still_load() {
+    use split::ParameterType::{HighCutoff, LowCutoff};
+
+    let (params, _host) = split::RawParameters::default_with_mock();
+    params
+        .load_named_state(&hashed_chunk(&[("high cutoff", 0.5), ("Removed", 0.1)]))
+        .unwrap();
+    assert_eq!(params.get(LowCutoff), 0.0);
+    assert_eq!(params.get(HighCutoff), 0.5);
+}
+
+#[test]
+fn malformed_named_chunks_are_rejected() {
+    use vst_utils::state::StateError;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    let chunk = params.save_named_state();
+    assert_eq!(
+        params.load_named_state(&chunk[..chunk.len() - 1]),
+        Err(StateError::Malformed)
+    );
+    assert_eq!(
+        params.load_named_state(b"nope nope nope"),
+        Err(StateError::BadMagic)
+    );
+}
@@ -0,0 +1,67 @@
+//! The `Arbitrary` impl and `randomize`, fed with fixed and pseudo-random
+//! bytes, and the `proptest` strategies, run against the shared table.
+
+mod common;
+
+use arbitrary::{Arbitrary, Unstructured};
+use common::{ParameterType, RawParameters};
+use proptest::prelude::*;
+use vst::plugin::PluginParameters;
+use vst_utils::strategies::parameter_values;
+
+/// Bytes from a small xorshift generator, so the test is reproducible.
+fn bytes(seed: u32, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_parameters_are_in_the_table() {
+    let data = bytes(1, 256);
+    let mut u = Unstructured::new(&data);
+    let mut seen = Vec::new();
+    while !u.is_empty() {
+        let parameter = ParameterType::arbitrary(&mut u).unwrap();
+        if !seen.contains(&parameter) {
+            seen.push(parameter);
+        }
+    }
+    assert_eq!(seen.len(), RawParameters::PARAMETER_COUNT as usize);
+}
+
+#[test]
+fn randomize_sets_normalized_values() {
+    let (params, host) = RawParameters::default_with_mock();
+    for seed in 1..50 {
+        let data = bytes(seed, 64);
+        params.randomize(&mut Unstructured::new(&data)).unwrap();
+        for index in RawParameters::index_range() {
+            let value = params.get_parameter(index);
+            assert!((0.0..=1.0).contains(&value), "{} at {}", value, index);
+        }
+    }
+    // Running out of bytes still gives values in range.
+    params.randomize(&mut Unstructured::new(&[])).unwrap();
+    for index in RawParameters::index_range() {
+        assert!((0.0..=1.0).contains(&params.get_parameter(index)));
+    }
+    assert!(host.take_events().is_empty());
+}
+
+proptest! {
+    #[test]
+    fn set_then_get((index, value) in parameter_values(RawParameters::descriptors())) {
+        let (raw, _host) = RawParameters::default_with_mock();
+        // The shared table's meter is readonly, so the host can't set it.
+        prop_assume!(index != 2);
+        raw.set_parameter(index, value);
+        prop_assert!((raw.get_parameter(index) - value).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,356 @@
+//! The table's compile-time checks and metadata.
+
+#[macro_use]
+mod common;
+
+use common::{ParameterType, RawParameters};
+
+#[test]
+fn steps_column() {
+    assert_eq!(RawParameters::get_step_count(ParameterType::Mode), Some(4));
+    assert_eq!(RawParameters::get_step_count(ParameterType::Volume), None);
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set(0.7, ParameterType::Mode);
+    assert_eq!(params.get_discrete(ParameterType::Mode), 2);
+    params.set(1.0, ParameterType::Mode);
+    assert_eq!(params.get_discrete(ParameterType::Mode), 3);
+    assert_eq!(params.get_discrete(ParameterType::Volume), 0);
+}
+
+#[test]
+fn group_column() {
+    assert_eq!(
+        RawParameters::get_parameter_group(ParameterType::Cutoff),
+        "Filter"
+    );
+    assert_eq!(
+        RawParameters::get_parameter_group(ParameterType::Volume),
+        ""
+    );
+}
+
+/// A table from `define_parameters!`, without importing the vst_utils macros.
+mod defined {
+    use vst::host::Host;
+    use vst::plugin::HostCallback;
+    use vst::util::AtomicFloat;
+
+    pub struct RawParameters {
+        pub gain: AtomicFloat,
+        pub mix: AtomicFloat,
+        pub host: HostCallback,
+    }
+
+    pub struct Parameters {
+        pub gain: f32,
+        pub mix: f32,
+    }
+
+    impl From<&RawParameters> for Parameters {
+        fn from(raw: &RawParameters) -> Self {
+            Parameters {
+                gain: raw.gain.get(),
+                mix: raw.mix.get(),
+            }
+        }
+    }
+
+    pub fn parameters() -> RawParameters {
+        RawParameters::default_with_mock().0
+    }
+
+    vst_utils::define_parameters! {
+        RawParameters, pub ParameterType, parameter_table;
+        Gain, 0, "Gain", gain, 0.5, vst_utils::make_strings_db;
+        Mix, 1, "Mix", mix, 1.0, vst_utils::make_strings_percent, short_name = "Wet";
+    }
+
+    parameter_table! {assert_contiguous_indices}
+}
+
+#[test]
+fn define_parameters_defines_the_enum() {
+    use vst::plugin::PluginParameters;
+
+    let mix = defined::ParameterType::Mix;
+    let copy = mix;
+    assert!(copy == mix);
+    assert_eq!(i32::from(mix), 1);
+    assert_eq!(mix.to_string(), "Mix");
+    assert_eq!(format!("{:?}", mix), "Mix(1)");
+    assert_eq!(defined::RawParameters::PARAMETER_COUNT, 2);
+
+    let params = defined::parameters();
+    assert_eq!(params.get(mix), 1.0);
+    assert_eq!(params.get_parameter_short_name(1), "Wet");
+    assert_eq!(params.get_parameter_text(1), "100");
+}
+
+#[test]
+fn cc_column() {
+    assert_eq!(RawParameters::cc_for_param(ParameterType::Cutoff), Some(74));
+    assert_eq!(RawParameters::cc_for_param(ParameterType::Volume), None);
+    assert_eq!(RawParameters::param_for_cc(74), Some(ParameterType::Cutoff));
+    assert_eq!(RawParameters::param_for_cc(7), None);
+}
+
+#[test]
+fn mod_range_column() {
+    assert_eq!(RawParameters::modulation_range(ParameterType::Volume), 0.25);
+    assert_eq!(RawParameters::modulation_range(ParameterType::Cutoff), 0.0);
+}
+
+#[test]
+fn link_column() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_linked(0.75, ParameterType::Volume);
+    assert!(RawParameters::linked_partner(ParameterType::Volume) == Some(ParameterType::Meter));
+    assert!(RawParameters::linked_partner(ParameterType::Cutoff).is_none());
+    assert_eq!(params.get(ParameterType::Meter), 0.75);
+}
+
+#[test]
+fn display_step_column() {
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_denormalized(424.0, ParameterType::Cutoff);
+    assert_eq!(RawParameters::display_step(ParameterType::Cutoff), 10.0);
+    assert_eq!(
+        params.get_parameter_value(ParameterType::Cutoff).text,
+        "420"
+    );
+    assert_eq!(RawParameters::display_step(ParameterType::Volume), 0.0);
+}
+
+#[test]
+fn num_parameters_fills_in_info() {
+    let info = vst::plugin::Info {
+        parameters: RawParameters::num_parameters(),
+        ..Default::default()
+    };
+    assert_eq!(info.parameters, 4);
+}
+
+#[test]
+fn meta_lists_every_row() {
+    assert_eq!(RawParameters::INDEX_LIMIT, 4);
+    assert_eq!(
+        RawParameters::META,
+        [
+            (0, "Volume", 0.5),
+            (1, "Cutoff", 0.25),
+            (2, "Meter", 0.0),
+            (3, "Mode", 0.0),
+        ]
+    );
+}
+
+#[test]
+fn parameter_by_name_ignores_case() {
+    assert_eq!(
+        RawParameters::parameter_by_name("Volume"),
+        Some(ParameterType::Volume)
+    );
+    assert_eq!(
+        RawParameters::parameter_by_name("CUTOFF"),
+        Some(ParameterType::Cutoff)
+    );
+    assert_eq!(
+        RawParameters::parameter_by_name("meter"),
+        Some(ParameterType::Meter)
+    );
+    assert_eq!(
+        RawParameters::parameter_by_name("freq"),
+        Some(ParameterType::Cutoff)
+    );
+    assert_eq!(RawParameters::get_aliases(ParameterType::Cutoff), ["Freq"]);
+    assert_eq!(RawParameters::parameter_by_name("Volumes"), None);
+    assert_eq!(RawParameters::parameter_by_name(""), None);
+}
+
+#[test]
+fn display_name_column() {
+    use vst::plugin::PluginParameters;
+
+    let (params, _host) = RawParameters::default_with_mock();
+    assert_eq!(params.get_parameter_name(2), "Output Meter");
+    assert_eq!(ParameterType::Meter.to_string(), "Meter");
+    assert_eq!(params.get_parameter_name(1), "Cutoff");
+}
+
+/// Rows without range columns aren't checked against `[0, 1]`.
+mod unranged_default {
+    use vst::host::Host;
+    use vst::plugin::HostCallback;
+    use vst::util::AtomicFloat;
+    use vst_utils::*;
+
+    pub struct RawParameters {
+        pub tuning: AtomicFloat,
+        pub host: HostCallback,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum ParameterType {
+        Tuning,
+    }
+
+    pub struct Parameters {
+        pub tuning: f32,
+    }
+
+    impl From<&RawParameters> for Parameters {
+        fn from(raw: &RawParameters) -> Self {
+            Parameters {
+                tuning: raw.tuning.get(),
+            }
+        }
+    }
+
+    macro_rules! table {
+        ($macro:ident) => {
+            $macro! {
+                RawParameters, ParameterType;
+                ParameterType::Tuning, 0, "Tuning", tuning, 440.0, make_strings_hz;
+            }
+        };
+    }
+
+    pub fn parameters() -> RawParameters {
+        RawParameters::default_with_mock().0
+    }
+
+    impl_all!(RawParameters, ParameterType, table);
+}
+
+#[test]
+fn unranged_default_is_kept() {
+    let params = unranged_default::parameters();
+    assert_eq!(params.tuning.get(), 440.0);
+}
+
+#[test]
+fn curve_column() {
+    assert_eq!(
+        RawParameters::get_curve(ParameterType::Meter),
+        vst_utils::Curve::Exp
+    );
+    assert_eq!(
+        RawParameters::get_curve(ParameterType::Cutoff),
+        vst_utils::Curve::Linear
+    );
+
+    let (params, _host) = RawParameters::default_with_mock();
+    params.set_denormalized(0.25, ParameterType::Meter);
+    assert!(params.get(ParameterType::Meter) > 0.25);
+    assert!((params.get_denormalized(ParameterType::Meter) - 0.25).abs() < 1e-5);
+}
+
+#[test]
+fn descriptors_describe_every_row() {
+    let descriptors = RawParameters::descriptors();
+    assert_eq!(descriptors.len(), 4);
+    assert_eq!(
+        descriptors[1],
+        vst_utils::ParameterDescriptor {
+            index: 1,
+            name: "Cutoff",
+            default: 0.25,
+            min: 20.0,
+            max: 20000.0,
+            unit: "Hz",
+            steps: 0,
+            kind: vst_utils::ParameterKind::Float,
+        }
+    );
+    assert_eq!(descriptors[3].steps, 4);
+    assert_eq!(descriptors[3].kind, vst_utils::ParameterKind::Int);
+    assert_eq!(descriptors[0].unit, "");
+}
+
+#[test]
+fn debug_includes_the_index() {
+    assert_eq!(format!("{:?}", ParameterType::Volume), "Volume(0)");
+    assert_eq!(format!("{:?}", ParameterType::Mode), "Mode(3)");
+    assert_eq!(
+        format!("{:?}", RawParameters::parameter_by_name("cutoff")),
+        "Some(Cutoff(1))"
+    );
+}
+
+/// A pan from -50 to 50 with its center at 0.
+mod bipolar {
+    use vst::host::Host;
+    use vst::plugin::HostCallback;
+    use vst::util::AtomicFloat;
+    use vst_utils::*;
+
+    pub struct RawParameters {
+        pub pan: AtomicFloat,
+        pub host: HostCallback,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum ParameterType {
+        Pan,
+    }
+
+    pub struct Parameters {
+        pub pan: f32,
+    }
+
+    impl From<&RawParameters> for Parameters {
+        fn from(raw: &RawParameters) -> Self {
+            Parameters {
+                pan: raw.get_denormalized(ParameterType::Pan),
+            }
+        }
+    }
+
+    macro_rules! table {
+        ($macro:ident) => {
+            $macro! {
+                RawParameters, ParameterType;
+                ParameterType::Pan, 0, "Pan", pan, 0.5, |pan| make_strings_prec(pan, "", 0),
+                    min = -50.0, max = 50.0, bipolar = true;
+            }
+        };
+    }
+
+    impl_all!(RawParameters, ParameterType, table);
+}
+
+#[test]
+fn bipolar_text_is_signed() {
+    use vst::plugin::PluginParameters;
+
+    let (params, _host) = bipolar::RawParameters::default_with_mock();
+    assert_eq!(params.get_parameter_text(0), "0");
+    params.set_parameter(0, 1.0);
+    assert_eq!(params.get_parameter_text(0), "+50");
+    params.set_parameter(0, 0.0);
+    assert_eq!(params.get_parameter_text(0), "-50");
+    params.set_parameter(0, 0.6);
+    assert_eq!(params.get_parameter_text(0), "+10");
+}
+
+#[test]
+fn index_range_covers_every_parameter() {
+    assert_eq!(
+        RawParameters::index_range().len(),
+        RawParameters::PARAMETER_COUNT as usize
+    );
+    assert_eq!(RawParameters::index_range(), 0..4);
+}
+
+#[test]
+fn parameter_type_from_name() {
+    use std::convert::TryFrom;
+    use vst_utils::ParameterNameError;
+
+    assert_eq!(ParameterType::try_from("Volume"), Ok(ParameterType::Volume));
+    assert_eq!(ParameterType::try_from("freq"), Ok(ParameterType::Cutoff));
+    let error = ParameterType::try_from("Resonance").unwrap_err();
+    assert_eq!(error, ParameterNameError("Resonance".to_string()));
+    assert_eq!(error.to_string(), "no parameter is named \"Resonance\"");
+}
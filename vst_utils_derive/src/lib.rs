@@ -0,0 +1,302 @@
+//! `#[derive(PluginParameters)]` for vst_utils. This builds the same table the
+//! declarative macros use out of `#[param(...)]` attributes on the raw
+//! parameters struct, then hands it to `impl_all!`.
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Derives the parameter enum and everything `impl_all!` generates for a raw
+/// parameters struct. Each parameter field is annotated like
+/// `#[param(index = 0, name = "Volume", default = 0.5)]`, and may also set
+/// `variant = Volume` (defaults to the field name in CamelCase),
+/// `string = <formatter>` (defaults to `vst_utils::make_strings(x, "")`),
+/// or any of the optional table columns documented on `impl_all!`.
+/// The enum is named by `#[param_type(ParameterType)]` on the struct, and
+/// defaults to `ParameterType`, and implements `Debug` with `impl_debug!`.
+/// Fields without `#[param]` (such as `host`) are ignored. The vst_utils
+/// macros must be in scope, just as for `impl_all!`.
+#[proc_macro_derive(PluginParameters, attributes(param, param_type))]
+pub fn derive_plugin_parameters(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(output) => output.parse().unwrap(),
+        Err(message) => format!("compile_error!({:?});", message).parse().unwrap(),
+    }
+}
+
+struct Row {
+    field_name: String,
+    variant: String,
+    index: String,
+    name: String,
+    default: String,
+    string: String,
+    columns: Vec<(String, String)>,
+}
+
+fn expand(input: TokenStream) -> Result<String, String> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut parameter_type = "ParameterType".to_string();
+    let mut visibility = String::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(group)) = tokens.get(i + 1) {
+                    if let Some(name) = parse_param_type(group.stream()) {
+                        parameter_type = name;
+                    }
+                }
+                i += 2;
+            }
+            TokenTree::Ident(ident) if ident.to_string() == "pub" => {
+                visibility.push_str("pub");
+                if let Some(TokenTree::Group(group)) = tokens.get(i + 1) {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        visibility.push_str(&group.to_string());
+                        i += 1;
+                    }
+                }
+                i += 1;
+            }
+            TokenTree::Ident(ident) if ident.to_string() == "struct" => break,
+            _ => return Err("PluginParameters can only be derived for structs".to_string()),
+        }
+    }
+
+    let raw_parameters = match tokens.get(i + 1) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected a struct name".to_string()),
+    };
+    let fields = match tokens.get(i + 2) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        _ => {
+            return Err(
+                "PluginParameters can only be derived for non-generic structs with named fields"
+                    .to_string(),
+            )
+        }
+    };
+
+    let mut rows = Vec::new();
+    for field in split_fields(fields) {
+        if let Some(row) = parse_field(&field)? {
+            rows.push(row);
+        }
+    }
+
+    let table = format!("__vst_utils_table_{}", raw_parameters);
+    let mut output = String::new();
+    output.push_str("#[derive(Clone, Copy, PartialEq, Eq)]\n");
+    output.push_str(&format!("{} enum {} {{\n", visibility, parameter_type));
+    for row in &rows {
+        output.push_str(&format!("    {},\n", row.variant));
+    }
+    output.push_str("}\n\n");
+    output.push_str(&format!("macro_rules! {} {{\n", table));
+    output.push_str("    ($macro:ident) => {\n");
+    output.push_str(&format!(
+        "        $macro! {{\n            {}, {};\n",
+        raw_parameters, parameter_type
+    ));
+    for row in &rows {
+        output.push_str(&format!(
+            "            {}::{}, {}, {}, {}, {}, {}",
+            parameter_type,
+            row.variant,
+            row.index,
+            row.name,
+            row.field_name,
+            row.default,
+            row.string
+        ));
+        for (column, value) in &row.columns {
+            output.push_str(&format!(", {} = {}", column, value));
+        }
+        output.push_str(";\n");
+    }
+    output.push_str("        }\n    };\n}\n\n");
+    output.push_str(&format!(
+        "impl_all!({}, {}, {});\n",
+        raw_parameters, parameter_type, table
+    ));
+    output.push_str(&format!("{}! {{impl_debug}}\n", table));
+    Ok(output)
+}
+
+/// Returns `Name` if `stream` is the inside of `#[param_type(Name)]`.
+fn parse_param_type(stream: TokenStream) -> Option<String> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    match (tokens.first(), tokens.get(1)) {
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(group)))
+            if ident.to_string() == "param_type" =>
+        {
+            Some(group.stream().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Splits a struct's fields on commas which aren't inside of a group or
+/// between the angle brackets of a type (ex: the comma in `HashMap<K, V>`).
+fn split_fields(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut fields = vec![Vec::new()];
+    let mut angle_depth = 0;
+    let mut after_minus = false;
+    for token in stream {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                '<' => angle_depth += 1,
+                // The `>` of `->` in a type such as `Box<dyn Fn() -> f32>`.
+                '>' if after_minus => {}
+                '>' if angle_depth > 0 => angle_depth -= 1,
+                ',' if angle_depth == 0 => {
+                    fields.push(Vec::new());
+                    after_minus = false;
+                    continue;
+                }
+                _ => {}
+            }
+            after_minus = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            after_minus = false;
+        }
+        fields.last_mut().unwrap().push(token);
+    }
+    fields.retain(|field| !field.is_empty());
+    fields
+}
+
+/// Splits the inside of `#[param(...)]` into its `key = value` arguments.
+/// Values are expressions, where `<` may be a comparison (ex: `string = |x|
+/// if x < 0.5 { ... }`), so this only splits on a comma which isn't inside
+/// of a group and is followed by another `key =`.
+fn split_arguments(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    let mut arguments = vec![Vec::new()];
+    for (i, token) in tokens.iter().enumerate() {
+        if let TokenTree::Punct(punct) = token {
+            if punct.as_char() == ',' && starts_argument(&tokens[i + 1..]) {
+                arguments.push(Vec::new());
+                continue;
+            }
+        }
+        arguments.last_mut().unwrap().push(token.clone());
+    }
+    arguments.retain(|argument| !argument.is_empty());
+    arguments
+}
+
+/// Returns whether `tokens` begins with `key =` (but not `key ==`), or is
+/// empty, as after a trailing comma.
+fn starts_argument(tokens: &[TokenTree]) -> bool {
+    match (tokens.first(), tokens.get(1)) {
+        (None, _) => true,
+        (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(equals))) => {
+            equals.as_char() == '=' && equals.spacing() == Spacing::Alone
+        }
+        _ => false,
+    }
+}
+
+/// Parses one struct field, returning None if it has no `#[param]` attribute.
+fn parse_field(field: &[TokenTree]) -> Result<Option<Row>, String> {
+    let mut attribute = None;
+    let mut i = 0;
+    while let Some(TokenTree::Punct(punct)) = field.get(i) {
+        if punct.as_char() != '#' {
+            break;
+        }
+        if let Some(TokenTree::Group(group)) = field.get(i + 1) {
+            let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+            if let (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(arguments))) =
+                (tokens.first(), tokens.get(1))
+            {
+                if ident.to_string() == "param" {
+                    attribute = Some(arguments.stream());
+                }
+            }
+        }
+        i += 2;
+    }
+
+    let attribute = match attribute {
+        Some(attribute) => attribute,
+        None => return Ok(None),
+    };
+
+    let field_name = field[i..]
+        .iter()
+        .take_while(|token| match token {
+            TokenTree::Punct(punct) => punct.as_char() != ':',
+            _ => true,
+        })
+        .filter_map(|token| match token {
+            TokenTree::Ident(ident) if ident.to_string() != "pub" => Some(ident.to_string()),
+            _ => None,
+        })
+        .last()
+        .ok_or_else(|| "expected a field name".to_string())?;
+
+    let mut row = Row {
+        variant: to_camel_case(&field_name),
+        field_name,
+        index: String::new(),
+        name: String::new(),
+        default: String::new(),
+        string: "|x| vst_utils::make_strings(x, \"\")".to_string(),
+        columns: Vec::new(),
+    };
+
+    for argument in split_arguments(attribute) {
+        let (key, value) = match (argument.first(), argument.get(1)) {
+            (Some(TokenTree::Ident(key)), Some(TokenTree::Punct(equals)))
+                if equals.as_char() == '=' && argument.len() > 2 =>
+            {
+                let value: TokenStream = argument[2..].iter().cloned().collect();
+                (key.to_string(), value.to_string())
+            }
+            _ => {
+                return Err(format!(
+                    "expected `key = value` in #[param] on `{}`",
+                    row.field_name
+                ))
+            }
+        };
+        match key.as_str() {
+            "index" => row.index = value,
+            "name" => row.name = value,
+            "default" => row.default = value,
+            "variant" => row.variant = value,
+            "string" => row.string = value,
+            _ => row.columns.push((key, value)),
+        }
+    }
+
+    for (key, value) in &[
+        ("index", &row.index),
+        ("name", &row.name),
+        ("default", &row.default),
+    ] {
+        if value.is_empty() {
+            return Err(format!(
+                "#[param] on `{}` is missing `{}`",
+                row.field_name, key
+            ));
+        }
+    }
+    Ok(Some(row))
+}
+
+fn to_camel_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
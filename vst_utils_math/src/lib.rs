@@ -0,0 +1,170 @@
+//! The easing and conversion functions behind `vst_utils`' curves and
+//! formatters. These only do `f32` math, so this crate also builds without
+//! `std`, for embedded targets and plugin sandboxes. Disable the default `std`
+//! feature and enable `libm`, which provides the float functions `std` would.
+//! The tests below describe how to check the `no_std` build.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("vst_utils_math needs either the `std` or the `libm` feature");
+
+#[cfg(feature = "std")]
+fn powf(x: f32, y: f32) -> f32 {
+    x.powf(y)
+}
+
+#[cfg(feature = "std")]
+fn log2(x: f32) -> f32 {
+    x.log2()
+}
+
+#[cfg(feature = "std")]
+fn log10(x: f32) -> f32 {
+    x.log10()
+}
+
+#[cfg(not(feature = "std"))]
+use libm::{log10f as log10, log2f as log2, powf};
+
+pub fn ease_in_expo(x: f32) -> f32 {
+    ease_in_expo_with(x, 10.0)
+}
+
+pub fn ease_in_expo_with(x: f32, steepness: f32) -> f32 {
+    if x <= 0.0 {
+        0.0
+    } else if x >= 1.0 {
+        1.0
+    } else if steepness <= 0.0 {
+        x
+    } else {
+        (powf(2.0, steepness * x) - 1.0) / (powf(2.0, steepness) - 1.0)
+    }
+}
+
+pub fn ease_in_expo_inv(y: f32) -> f32 {
+    if y <= 0.0 {
+        0.0
+    } else if y >= 1.0 {
+        1.0
+    } else {
+        log2(y * (powf(2.0, 10.0) - 1.0) + 1.0) / 10.0
+    }
+}
+
+pub fn ease_out_expo(x: f32) -> f32 {
+    if x <= 0.0 {
+        0.0
+    } else if x >= 1.0 {
+        1.0
+    } else {
+        (1.0 - powf(2.0, -10.0 * x)) / (1.0 - powf(2.0, -10.0))
+    }
+}
+
+pub fn ease_in_out_expo(x: f32) -> f32 {
+    if x <= 0.0 {
+        0.0
+    } else if x >= 1.0 {
+        1.0
+    } else if x < 0.5 {
+        ease_in_expo(2.0 * x) / 2.0
+    } else {
+        0.5 + ease_out_expo(2.0 * x - 1.0) / 2.0
+    }
+}
+
+pub fn smoothstep(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    x * x * (3.0 - 2.0 * x)
+}
+
+pub fn smootherstep(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    x * x * x * (x * (x * 6.0 - 15.0) + 10.0)
+}
+
+pub fn linear_to_db(amp: f32) -> f32 {
+    if amp <= 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        20.0 * log10(amp)
+    }
+}
+
+pub fn db_to_linear(db: f32) -> f32 {
+    powf(10.0, db / 20.0)
+}
+
+pub fn lerp(min: f32, max: f32, t: f32) -> f32 {
+    min + t * (max - min)
+}
+
+pub fn inv_lerp(min: f32, max: f32, v: f32) -> f32 {
+    #[allow(clippy::float_cmp)]
+    if min == max {
+        return 0.0;
+    }
+    (v - min) / (max - min)
+}
+
+/// There is no CI for the `no_std` build, so check it locally. From the
+/// workspace root, first run `rustup target add thumbv7em-none-eabihf`, then
+/// build for a target without `std`:
+/// ```text
+/// cargo build -p vst_utils_math --no-default-features --features vst_utils_math/libm --target thumbv7em-none-eabihf
+/// ```
+/// and run these tests against `libm` instead of `std`:
+/// ```text
+/// cargo test -p vst_utils_math --no-default-features --features vst_utils_math/libm
+/// ```
+/// Inside `vst_utils_math`, `--features libm` works as well.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ease_in_expo_inv_round_trips() {
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            assert!(
+                (ease_in_expo_inv(ease_in_expo(x)) - x).abs() < 1.0e-5,
+                "{}",
+                x
+            );
+        }
+        assert_eq!(ease_in_expo_inv(-0.5), 0.0);
+        assert_eq!(ease_in_expo_inv(1.5), 1.0);
+    }
+
+    #[test]
+    fn db_conversions() {
+        assert_eq!(linear_to_db(1.0), 0.0);
+        assert_eq!(linear_to_db(0.0), f32::NEG_INFINITY);
+        assert_eq!(linear_to_db(-1.0), f32::NEG_INFINITY);
+        assert!((linear_to_db(0.5) + 6.0206).abs() < 1.0e-3);
+        assert_eq!(db_to_linear(0.0), 1.0);
+        assert!((db_to_linear(linear_to_db(0.25)) - 0.25).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn ease_in_expo_stays_in_range() {
+        assert_eq!(ease_in_expo(1.5), 1.0);
+        assert!((ease_in_expo(1.0) - 1.0).abs() < 1.0e-6);
+        assert_eq!(ease_in_expo(-0.5), 0.0);
+        assert_eq!(ease_out_expo(1.5), 1.0);
+        assert_eq!(ease_in_out_expo(1.5), 1.0);
+    }
+
+    #[test]
+    fn s_curves_hit_their_endpoints() {
+        for curve in [smoothstep, smootherstep] {
+            assert_eq!(curve(0.0), 0.0);
+            assert_eq!(curve(1.0), 1.0);
+            assert_eq!(curve(-1.0), 0.0);
+            assert_eq!(curve(2.0), 1.0);
+        }
+        assert_eq!(smoothstep(0.5), 0.5);
+        assert_eq!(smootherstep(0.5), 0.5);
+    }
+}